@@ -1,9 +1,11 @@
 use std::{
+    collections::BTreeMap,
     ffi::{
         CStr,
         CString,
     },
     panic,
+    slice,
 };
 use std::os::raw::c_char;
 
@@ -20,6 +22,68 @@ pub extern "C" fn compile(input: *const c_char, json: *const c_char) -> *const c
     }
 }
 
+/// # Safety
+///
+/// `input`, `json` and `locale` must be valid pointers to NUL-terminated UTF-8 strings.
+#[no_mangle]
+pub unsafe extern "C" fn compile_with_locale(input: *const c_char, json: *const c_char, locale: *const c_char) -> *const c_char {
+    let input = unsafe { CStr::from_ptr(input) }.to_str().unwrap();
+    let json = unsafe { CStr::from_ptr(json) }.to_str().unwrap();
+    let locale = unsafe { CStr::from_ptr(locale) }.to_str().unwrap();
+    let result = panic::catch_unwind(|| {
+        let options = teuchiudon_compiler::CompileOptions {
+            locale: locale.parse().unwrap_or_default(),
+            ..Default::default()
+        };
+        teuchiudon_compiler::compile_with_options(input, json, &options)
+    });
+    match result {
+        Ok(output) => CString::new(output).unwrap().into_raw(),
+        Err(_) => CString::new("!panic").unwrap().into_raw(),
+    }
+}
+
+/// Compiles the `len` source files named by `names` with the contents in `inputs`, starting from the file named `entry`.
+///
+/// # Safety
+///
+/// `names` and `inputs` must be valid pointers to `len` pointers to NUL-terminated UTF-8 strings,
+/// and `entry`, `json` and `locale` must be valid pointers to NUL-terminated UTF-8 strings.
+#[no_mangle]
+pub unsafe extern "C" fn compile_files(
+    names: *const *const c_char,
+    inputs: *const *const c_char,
+    len: usize,
+    entry: *const c_char,
+    json: *const c_char,
+    locale: *const c_char,
+) -> *const c_char {
+    let names = unsafe { slice::from_raw_parts(names, len) };
+    let inputs = unsafe { slice::from_raw_parts(inputs, len) };
+    let files =
+        names.iter()
+        .zip(inputs)
+        .map(|(name, input)| (
+            unsafe { CStr::from_ptr(*name) }.to_str().unwrap().to_owned(),
+            unsafe { CStr::from_ptr(*input) }.to_str().unwrap().to_owned(),
+        ))
+        .collect::<BTreeMap<_, _>>();
+    let entry = unsafe { CStr::from_ptr(entry) }.to_str().unwrap();
+    let json = unsafe { CStr::from_ptr(json) }.to_str().unwrap();
+    let locale = unsafe { CStr::from_ptr(locale) }.to_str().unwrap();
+    let result = panic::catch_unwind(|| {
+        let options = teuchiudon_compiler::CompileOptions {
+            locale: locale.parse().unwrap_or_default(),
+            ..Default::default()
+        };
+        teuchiudon_compiler::compile_files_with_options(&files, entry, json, &options)
+    });
+    match result {
+        Ok(output) => CString::new(output).unwrap().into_raw(),
+        Err(_) => CString::new("!panic").unwrap().into_raw(),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn free_str(ptr: *mut c_char) {
     std::mem::drop(unsafe { CString::from_raw(ptr) });
@@ -252,6 +252,6 @@ impl CodeName {
 
 impl TyName {
     pub fn indirect() -> Self {
-        Self::from("SysytemUInt32".to_owned())
+        Self::from("SystemUInt32".to_owned())
     }
 }
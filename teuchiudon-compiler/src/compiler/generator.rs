@@ -9,10 +9,14 @@ use teuchiudon_parser::semantics::{
         label::{
             CodeLabel,
             DataLabel,
+            DataLabelKind,
             ExternLabel,
         },
         literal::Literal,
-        method::Method,
+        method::{
+            Method,
+            MethodParamInOut,
+        },
         var::Var,
     },
 };
@@ -26,8 +30,13 @@ use super::routine;
 pub fn generate_data_part<'input>(
     context: &Context<'input>
 ) -> Vec<Instruction> {
-    context.var_labels.values().flat_map(|x| routine::decl_data(x.clone(), AsmLiteral::Null))
-    .chain(context.literal_labels.values().flat_map(|x| routine::decl_data(x.clone(), AsmLiteral::Null)))
+    context.var_labels.iter()
+    .filter(|(var, _)| var.actual_name.borrow().is_none())
+    .flat_map(|(_, x)| routine::decl_data(x.clone(), AsmLiteral::Null))
+    .chain(
+        context.literal_labels.iter()
+        .flat_map(|(literal, x)| routine::decl_data(x.clone(), if literal.is_this() { AsmLiteral::This } else { AsmLiteral::Null }))
+    )
     .collect()
 }
 
@@ -74,6 +83,14 @@ pub fn visit_top_stat<'input: 'context, 'context>(
             empty(),
         ast::TopStatDetail::VarBind { access_attr, sync_attr, var_bind } =>
             visit_var_bind_top_stat(context, access_attr.clone(), sync_attr.clone(), var_bind.clone()),
+        ast::TopStatDetail::EnumBind { enum_bind: _ } =>
+            empty(),
+        ast::TopStatDetail::NewtyBind { newty_bind: _ } =>
+            empty(),
+        ast::TopStatDetail::ModBind { mod_bind: _ } =>
+            empty(),
+        ast::TopStatDetail::UseBind { use_bind: _ } =>
+            empty(),
         ast::TopStatDetail::Stat { stat } =>
             visit_stat_top_stat(context, stat.clone()),
         _ =>
@@ -133,6 +150,8 @@ pub fn visit_stat<'input: 'context, 'context>(
     match stat.detail.as_ref() {
         ast::StatDetail::VarBind { var_bind } =>
             visit_var_bind_stat(context, var_bind.clone()),
+        ast::StatDetail::TyBind { ty_bind: _ } =>
+            empty(),
         ast::StatDetail::Expr { expr } =>
             visit_expr_stat(context, expr.clone()),
         _ =>
@@ -162,10 +181,40 @@ pub fn visit_expr<'input: 'context, 'context>(
         ast::ExprDetail::Term { term } =>
             visit_term(context, term.clone()),
         ast::ExprDetail::InfixOp { left, op, right } =>
-            visit_infix_op(context, left.clone(), op, right.clone())
+            visit_infix_op(context, left.clone(), op, right.clone()),
+        ast::ExprDetail::Coalescing { left, right, scrutinee, test, result } =>
+            visit_coalescing(context, left.clone(), right.clone(), scrutinee.clone(), test.clone(), result.clone()),
     }
 }
 
+fn visit_coalescing<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    left: Rc<ast::Expr<'input>>,
+    right: Rc<ast::Expr<'input>>,
+    scrutinee: Rc<Var>,
+    test: Rc<ast::PatternTest>,
+    result: Rc<Var>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let id = context.coalescing_id_factory.next_id();
+    let right_label = CodeLabel::from_name(&format!("coalescing[{}][right]", id));
+    let end_label = CodeLabel::from_name(&format!("coalescing[{}][end]", id));
+    let scrutinee = var_label(context, scrutinee);
+    let result = var_label(context, result);
+    Box::new(
+        visit_expr(context, left)
+        .chain(routine::set(scrutinee.clone()))
+        .chain(visit_pattern_test(context, scrutinee.clone(), test, right_label.clone()))
+        .chain(routine::get(scrutinee))
+        .chain(routine::set(result.clone()))
+        .chain(routine::jump(end_label.clone()))
+        .chain(routine::label(right_label))
+        .chain(visit_expr(context, right))
+        .chain(routine::set(result.clone()))
+        .chain(routine::label(end_label))
+        .chain(routine::get(result))
+    )
+}
+
 pub fn visit_infix_op<'input: 'context, 'context>(
     context: &'context Context<'input>,
     left: Rc<ast::Expr<'input>>,
@@ -175,8 +224,14 @@ pub fn visit_infix_op<'input: 'context, 'context>(
     match op {
         ast::Op::TyAccess =>
             visit_ty_access_op(context, left, right),
+        ast::Op::Access =>
+            visit_access_op(context, left, right),
         ast::Op::EvalFn =>
             visit_eval_fn_op(context, left, right),
+        ast::Op::CastOp =>
+            visit_cast_op(context, left, right),
+        ast::Op::Assign =>
+            visit_assign_op(context, left, right),
         _ =>
             error("infix_op".to_owned()),
     }
@@ -204,6 +259,14 @@ fn visit_ty_access_op<'input: 'context, 'context>(
     Box::new(routine::get(var_label(context, var.clone())))
 }
 
+fn visit_access_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    left: Rc<ast::Expr<'input>>,
+    _right: Rc<ast::Expr<'input>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    visit_expr(context, left)
+}
+
 fn visit_eval_fn_op<'input: 'context, 'context>(
     context: &'context Context<'input>,
     left: Rc<ast::Expr<'input>>,
@@ -213,7 +276,7 @@ fn visit_eval_fn_op<'input: 'context, 'context>(
         else {
             return error("eval_fn_op".to_owned());
         };
-    let ast::TermDetail::ApplyFn { args, as_fn } = term.detail.as_ref()
+    let ast::TermDetail::ApplyFn { ty_exprs: _, args, as_fn } = term.detail.as_ref()
         else {
             return error("eval_fn_op".to_owned());
         };
@@ -224,16 +287,42 @@ fn visit_eval_fn_op<'input: 'context, 'context>(
                 for (v, d) in f.fn_stats.vars.iter().zip(f.data.iter()) {
                     v.actual_name.replace(Some(d.clone()));
                 }
-                visit_stats_block(context, f.fn_stats.stats.clone())
+                let args =
+                    args.clone().into_iter()
+                    .flat_map(|x| {
+                        let count = x.expr.ty.instance.as_ref().map(|x| x.elem_count()).unwrap_or_default();
+                        visit_expr(context, x.expr.clone())
+                        .chain((0..count).flat_map(|_| routine::pop()))
+                    });
+                Box::new(
+                    args
+                    .chain(visit_stats_block(context, f.fn_stats.stats.clone()))
+                )
             },
             ast::AsFn::Method(m) => {
-                let args =
-                    Box::new(
-                        args.clone().into_iter().flat_map(|x| visit_expr(context, x.expr.clone()))
-                    );
+                let outs =
+                    right.data.borrow().clone().unwrap_or_default().into_iter()
+                    .filter_map(|x| match &x.kind {
+                        DataLabelKind::Var(v) => Some(var_label(context, v.clone())),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let mut in_iter = args.clone().into_iter();
+                let mut out_iter = outs.clone().into_iter();
+                let params =
+                    m.param_in_outs.iter()
+                    .map(|x| match x {
+                        MethodParamInOut::Out =>
+                            out_iter.next().map(routine::get).unwrap_or_else(|| error("eval_fn_op".to_owned())),
+                        _ =>
+                            in_iter.next().map(|x| visit_expr(context, x.expr.clone())).unwrap_or_else(|| error("eval_fn_op".to_owned())),
+                    })
+                    .collect::<Vec<_>>();
+                let results = m.to_result_order(&outs);
                 Box::new(
                     visit_expr(context, left)
-                    .chain(routine::call_method(args, method_label(context, m.clone())))
+                    .chain(routine::call_method(Box::new(params.into_iter().flatten()), method_label(context, m.clone())))
+                    .chain(results.into_iter().flat_map(routine::get))
                 )
             },
         }
@@ -242,6 +331,37 @@ fn visit_eval_fn_op<'input: 'context, 'context>(
     }
 }
 
+fn visit_cast_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    left: Rc<ast::Expr<'input>>,
+    _right: Rc<ast::Expr<'input>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    visit_expr(context, left)
+}
+
+fn visit_assign_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    left: Rc<ast::Expr<'input>>,
+    right: Rc<ast::Expr<'input>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let ast::ExprDetail::Term { term } = left.detail.as_ref()
+        else {
+            return error("assign_op".to_owned());
+        };
+    let ast::TermDetail::EvalVar { ident: _, var } = term.detail.as_ref()
+        else {
+            return error("assign_op".to_owned());
+        };
+    let Some(var) = var.borrow().clone()
+        else {
+            return error("assign_op".to_owned());
+        };
+    Box::new(
+        visit_expr(context, right)
+        .chain(routine::set(var_label(context, var)))
+    )
+}
+
 pub fn visit_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     term: Rc<ast::Term<'input>>,
@@ -249,10 +369,20 @@ pub fn visit_term<'input: 'context, 'context>(
     match term.detail.as_ref() {
         ast::TermDetail::Block { stats } =>
             visit_block_term(context, stats.clone()),
+        ast::TermDetail::Paren { expr } =>
+            visit_paren_term(context, expr.clone()),
+        ast::TermDetail::Tuple { exprs } =>
+            visit_tuple_term(context, exprs.clone()),
         ast::TermDetail::Literal { literal } =>
             visit_literal_term(context, literal.clone()),
+        ast::TermDetail::ThisLiteral { literal } =>
+            visit_literal_term(context, literal.clone()),
         ast::TermDetail::EvalVar { ident: _, var } =>
             visit_eval_var_term(context, var),
+        ast::TermDetail::If { condition, if_part, else_part, result } =>
+            visit_if_term(context, condition.clone(), if_part.clone(), else_part.clone(), result.clone()),
+        ast::TermDetail::Match { expr, match_arms, scrutinee, result, jump_table } =>
+            visit_match_term(context, expr.clone(), match_arms.clone(), scrutinee.clone(), result.clone(), jump_table.clone()),
         _ =>
             error("term".to_owned())
     }
@@ -265,6 +395,20 @@ fn visit_block_term<'input: 'context, 'context>(
     visit_stats_block(context, stats)
 }
 
+fn visit_paren_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    expr: Rc<ast::Expr<'input>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    visit_expr(context, expr)
+}
+
+fn visit_tuple_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    exprs: Vec<Rc<ast::Expr<'input>>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    Box::new(exprs.into_iter().flat_map(|x| visit_expr(context, x)))
+}
+
 fn visit_literal_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     literal: Rc<Literal>,
@@ -284,6 +428,234 @@ fn visit_eval_var_term<'input: 'context, 'context>(
     }
 }
 
+fn visit_if_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    condition: Rc<ast::Expr<'input>>,
+    if_part: Rc<ast::StatsBlock<'input>>,
+    else_part: Option<Rc<ast::StatsBlock<'input>>>,
+    result: Option<Rc<Var>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let id = context.if_id_factory.next_id();
+    let else_label = CodeLabel::from_name(&format!("if[{}][else]", id));
+    let end_label = CodeLabel::from_name(&format!("if[{}][end]", id));
+    let result = result.map(|x| var_label(context, x));
+
+    let else_part = match else_part {
+        Some(x) => visit_if_branch(context, x, result.clone(), end_label.clone()),
+        None => empty(),
+    };
+    let get_result = match result.clone() {
+        Some(x) => routine::get(x),
+        None => empty(),
+    };
+    Box::new(
+        visit_expr(context, condition)
+        .chain(routine::jump_if_false(else_label.clone()))
+        .chain(visit_if_branch(context, if_part, result, end_label.clone()))
+        .chain(routine::label(else_label))
+        .chain(else_part)
+        .chain(routine::label(end_label))
+        .chain(get_result)
+    )
+}
+
+fn visit_if_branch<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    stats_block: Rc<ast::StatsBlock<'input>>,
+    result: Option<Rc<DataLabel>>,
+    end_label: Rc<CodeLabel>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let ret = match stats_block.ret.parsed {
+        Some(_) => visit_expr(context, stats_block.ret.clone()),
+        None => empty(),
+    };
+    let set_result: Box<dyn Iterator<Item = Instruction>> = match result {
+        Some(x) => routine::set(x),
+        None => {
+            let count = stats_block.ret.ty.instance.as_ref().map(|x| x.elem_count()).unwrap_or_default();
+            Box::new((0..count).flat_map(|_| routine::pop()))
+        },
+    };
+    Box::new(
+        visit_stats_block(context, stats_block.clone())
+        .chain(ret)
+        .chain(set_result)
+        .chain(routine::jump(end_label))
+    )
+}
+
+fn visit_match_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    expr: Rc<ast::Expr<'input>>,
+    match_arms: Vec<Rc<ast::MatchArm<'input>>>,
+    scrutinee: Rc<Var>,
+    result: Option<Rc<Var>>,
+    jump_table: Option<Rc<ast::JumpTable>>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let id = context.match_id_factory.next_id();
+    let end_label = CodeLabel::from_name(&format!("match[{}][end]", id));
+    let arm_labels =
+        (0..match_arms.len())
+        .map(|i| CodeLabel::from_name(&format!("match[{}][arm][{}]", id, i)))
+        .collect::<Vec<_>>();
+    let scrutinee = var_label(context, scrutinee);
+    let result = result.map(|x| var_label(context, x));
+
+    let dispatch: Box<dyn Iterator<Item = Instruction> + 'context> = match jump_table {
+        Some(table) => {
+            let table_label = CodeLabel::from_name(&format!("match[{}][table]", id));
+            let fallback_label = match table.fallback {
+                Some(i) => arm_labels[i].clone(),
+                None => end_label.clone(),
+            };
+            let entries =
+                table.entries.iter()
+                .flat_map(|x| match x {
+                    Some(i) => routine::jump(arm_labels[*i].clone()),
+                    None => routine::jump(fallback_label.clone()),
+                })
+                .collect::<Vec<_>>();
+            let offset = var_label(context, table.offset.clone());
+            let addr = var_label(context, table.addr.clone());
+            let bodies =
+                match_arms.iter().enumerate()
+                .flat_map(|(i, x)| routine::label(arm_labels[i].clone()).chain(visit_match_arm(context, x.clone(), result.clone(), end_label.clone())))
+                .collect::<Vec<_>>();
+            Box::new(
+                visit_pattern_test(context, scrutinee.clone(), table.lower.clone(), fallback_label.clone())
+                .chain(visit_pattern_test(context, scrutinee.clone(), table.upper.clone(), fallback_label.clone()))
+                .chain(routine::call_method(
+                    Box::new(
+                        routine::get(scrutinee.clone())
+                        .chain(routine::get(literal_label(context, table.lower.operand.clone())))
+                        .chain(routine::get(offset.clone()))
+                    ),
+                    method_label(context, table.sub.clone())
+                ))
+                .chain(routine::call_method(
+                    Box::new(routine::get(offset).chain(routine::get(addr.clone()))),
+                    method_label(context, table.to_uint.clone())
+                ))
+                .chain(routine::call_method(
+                    Box::new(
+                        routine::get(addr.clone())
+                        .chain(routine::get(literal_label(context, table.stride.clone())))
+                        .chain(routine::get(addr.clone()))
+                    ),
+                    method_label(context, table.mul.clone())
+                ))
+                .chain(routine::call_method(
+                    Box::new(
+                        routine::indirect(table_label.clone())
+                        .chain(routine::get(addr.clone()))
+                        .chain(routine::get(addr.clone()))
+                    ),
+                    method_label(context, table.add.clone())
+                ))
+                .chain(routine::jump_indirect(addr))
+                .chain(routine::label(table_label))
+                .chain(entries)
+                .chain(bodies)
+            )
+        },
+        None => {
+            let test_labels =
+                (0..=match_arms.len())
+                .map(|i| CodeLabel::from_name(&format!("match[{}][test][{}]", id, i)))
+                .collect::<Vec<_>>();
+            let bodies =
+                match_arms.iter().enumerate()
+                .flat_map(|(i, x)|
+                    routine::label(test_labels[i].clone())
+                    .chain(visit_pattern(context, scrutinee.clone(), x.pattern.clone(), test_labels[i + 1].clone()))
+                    .chain(routine::label(arm_labels[i].clone()))
+                    .chain(visit_match_arm(context, x.clone(), result.clone(), end_label.clone()))
+                )
+                .collect::<Vec<_>>();
+            Box::new(
+                bodies.into_iter()
+                .chain(routine::label(test_labels[match_arms.len()].clone()))
+            )
+        },
+    };
+
+    let get_result = match result {
+        Some(x) => routine::get(x),
+        None => empty(),
+    };
+    Box::new(
+        visit_expr(context, expr)
+        .chain(routine::set(scrutinee))
+        .chain(dispatch)
+        .chain(routine::label(end_label))
+        .chain(get_result)
+    )
+}
+
+fn visit_match_arm<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    match_arm: Rc<ast::MatchArm<'input>>,
+    result: Option<Rc<DataLabel>>,
+    end_label: Rc<CodeLabel>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let set_result = match result {
+        Some(x) => routine::set(x),
+        None => empty(),
+    };
+    Box::new(
+        visit_expr(context, match_arm.expr.clone())
+        .chain(set_result)
+        .chain(routine::jump(end_label))
+    )
+}
+
+fn visit_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    scrutinee: Rc<DataLabel>,
+    pattern: Rc<ast::Pattern<'input>>,
+    next_label: Rc<CodeLabel>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    match pattern.detail.as_ref() {
+        ast::PatternDetail::Wildcard =>
+            empty(),
+        ast::PatternDetail::Literal { literal: _, test } =>
+            visit_pattern_test(context, scrutinee, test.clone(), next_label),
+        ast::PatternDetail::Range { left: _, right: _, lower, upper } =>
+            Box::new(
+                visit_pattern_test(context, scrutinee.clone(), lower.clone(), next_label.clone())
+                .chain(visit_pattern_test(context, scrutinee, upper.clone(), next_label))
+            ),
+        ast::PatternDetail::Tuple { patterns } =>
+            Box::new(
+                patterns.clone().into_iter()
+                .flat_map(move |x| visit_pattern(context, scrutinee.clone(), x, next_label.clone()))
+            ),
+        ast::PatternDetail::EvalVar { idents: _, var: _, literal: _, test } =>
+            visit_pattern_test(context, scrutinee, test.clone(), next_label),
+    }
+}
+
+fn visit_pattern_test<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    scrutinee: Rc<DataLabel>,
+    test: Rc<ast::PatternTest>,
+    next_label: Rc<CodeLabel>,
+) -> Box<dyn Iterator<Item = Instruction> + 'context> {
+    let cond = var_label(context, test.cond.clone());
+    Box::new(
+        routine::call_method(
+            Box::new(
+                routine::get_nth(scrutinee, test.index)
+                .chain(routine::get(literal_label(context, test.operand.clone())))
+                .chain(routine::get(cond.clone()))
+            ),
+            method_label(context, test.method.clone())
+        )
+        .chain(routine::get(cond))
+        .chain(routine::jump_if_false(next_label))
+    )
+}
+
 fn error(message: String) -> Box<dyn Iterator<Item = Instruction>> {
     Box::new(routine::comment(format!("Error detected: `{}`", message)))
 }
@@ -56,6 +56,15 @@ pub fn get(data: Rc<DataLabel>) -> Box<dyn Iterator<Item = Instruction>> {
     )
 }
 
+pub fn get_nth(data: Rc<DataLabel>, n: usize) -> Box<dyn Iterator<Item = Instruction>> {
+    Box::new(
+        data.to_name().into_iter()
+        .skip(n)
+        .take(1)
+        .map(|data| Instruction::Push(DataAddr::Label(data)))
+    )
+}
+
 pub fn set(data: Rc<DataLabel>) -> Box<dyn Iterator<Item = Instruction>> {
     Box::new(
         data.to_name().into_iter().rev()
@@ -87,6 +96,18 @@ pub fn jump(code: Rc<CodeLabel>) -> Box<dyn Iterator<Item = Instruction>> {
     ].into_iter())
 }
 
+pub fn jump_if_false(code: Rc<CodeLabel>) -> Box<dyn Iterator<Item = Instruction>> {
+    Box::new([
+        Instruction::JumpIfFalse(CodeAddr::Label(code.to_name()))
+    ].into_iter())
+}
+
+pub fn label(code: Rc<CodeLabel>) -> Box<dyn Iterator<Item = Instruction>> {
+    Box::new([
+        Instruction::Label(code.to_name())
+    ].into_iter())
+}
+
 pub fn decl_fn() -> Box<dyn Iterator<Item = Instruction>> {
     Box::new([
         // TODO
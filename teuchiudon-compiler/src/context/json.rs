@@ -1,16 +1,35 @@
 use std::collections::HashSet;
 use serde::Serialize;
 use serde_json;
+use teuchiudon_parser::Diagnostic;
 use super::Context;
 use crate::assembly::label::EvalLabel;
 
 #[derive(Serialize)]
 struct Compiled {
     output: String,
-    errors: Vec<String>,
+    errors: Vec<CompiledDiagnostic>,
+    warnings: Vec<CompiledDiagnostic>,
     default_values: Vec<DefaultValue>,
 }
 
+#[derive(Serialize)]
+struct CompiledDiagnostic {
+    #[serde(flatten)]
+    diagnostic: Diagnostic,
+    rendered: String,
+}
+
+impl CompiledDiagnostic {
+    fn new<'a>(diagnostic: Diagnostic, source: &impl Fn(Option<&str>) -> &'a str) -> Self {
+        let rendered = diagnostic.render(source(diagnostic.file.as_deref()));
+        Self {
+            diagnostic,
+            rendered,
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct DefaultValue {
     name: String,
@@ -19,7 +38,13 @@ struct DefaultValue {
 }
 
 impl<'input> Context<'input> {
-    pub fn output_to_json(&self, output: String, used_data: HashSet<String>) -> String {
+    pub fn output_to_json<'a>(
+        &self,
+        output: String,
+        used_data: HashSet<String>,
+        warnings: Vec<Diagnostic>,
+        source: impl Fn(Option<&str>) -> &'a str,
+    ) -> String {
         let default_values =
             self.valued_vars.iter()
             .map(|(var, pub_var)|
@@ -31,6 +56,7 @@ impl<'input> Context<'input> {
             )
             .chain(
                 self.literal_labels.iter()
+                .filter(|(literal, _)| !literal.is_this())
                 .map(|(literal, data)|
                     DefaultValue {
                         name: data.to_name()[0].real_name.clone(),
@@ -44,15 +70,17 @@ impl<'input> Context<'input> {
         let compiled = Compiled {
             output,
             errors: Vec::new(),
+            warnings: warnings.into_iter().map(|x| CompiledDiagnostic::new(x, &source)).collect(),
             default_values,
         };
         serde_json::to_string(&compiled).unwrap()
     }
 
-    pub fn errors_to_json(errors: Vec<String>) -> String {
+    pub fn errors_to_json<'a>(errors: Vec<Diagnostic>, source: impl Fn(Option<&str>) -> &'a str) -> String {
         let compiled = Compiled {
             output: String::new(),
-            errors,
+            errors: errors.into_iter().map(|x| CompiledDiagnostic::new(x, &source)).collect(),
+            warnings: Vec::new(),
             default_values: Vec::new(),
         };
         serde_json::to_string(&compiled).unwrap()
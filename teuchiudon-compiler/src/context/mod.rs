@@ -4,6 +4,7 @@ use std::{
     collections::HashMap,
     rc::Rc,
 };
+use teuchiudon_parser::context::id_factory::IdFactory;
 use teuchiudon_parser::semantics::elements::{
     element::ValueElement,
     ev::Ev,
@@ -35,6 +36,9 @@ pub struct Context<'input> {
     pub top_stats: Vec<Rc<TopStat<'input>>>,
     pub ev_stats: HashMap<Rc<Ev>, Rc<EvStats<'input>>>,
     pub valued_vars: HashMap<Rc<Var>, Rc<ValuedVar>>,
+    pub match_id_factory: IdFactory,
+    pub if_id_factory: IdFactory,
+    pub coalescing_id_factory: IdFactory,
 }
 
 impl<'input> Context<'input> {
@@ -71,6 +75,9 @@ impl<'input> Context<'input> {
                 context.valued_var_store.values()
                 .map(|x| (Var::get(context, x.qual.to_key(), x.name.clone()).unwrap(), x.clone()))
                 .collect(),
+            match_id_factory: IdFactory::new(),
+            if_id_factory: IdFactory::new(),
+            coalescing_id_factory: IdFactory::new(),
         }
     }
 }
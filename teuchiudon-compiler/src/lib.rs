@@ -2,11 +2,25 @@ pub mod assembly;
 pub mod compiler;
 pub mod context;
 
-use std::collections::HashSet;
+use std::collections::{
+    BTreeMap,
+    HashSet,
+};
+pub use teuchiudon_parser::context::options::Options as CompileOptions;
+pub use teuchiudon_parser::{
+    ErrorCode,
+    Explanation,
+    Locale,
+};
 use teuchiudon_parser::{
     context::Context as ParserContext,
+    Diagnostic,
     analize,
-    parse,
+    analize_files,
+    parse_files_partial,
+    parse_partial,
+    warnings,
+    warnings_files,
 };
 use self::{
     assembly::container::AsmContainer,
@@ -18,23 +32,77 @@ use self::{
 };
 
 pub fn compile(input: &str, json: &str) -> String {
-    match compile_result(input, json) {
-        Ok((context, output, used_data)) =>
-            context.output_to_json(output, used_data),
+    compile_with_options(input, json, &CompileOptions::default())
+}
+
+pub fn compile_with_options(input: &str, json: &str, options: &CompileOptions) -> String {
+    compile_to_json(compile_result(input, json, options), options.locale, |_| input)
+}
+
+pub fn compile_files(files: &BTreeMap<String, String>, entry: &str, json: &str) -> String {
+    compile_files_with_options(files, entry, json, &CompileOptions::default())
+}
+
+pub fn compile_files_with_options(files: &BTreeMap<String, String>, entry: &str, json: &str, options: &CompileOptions) -> String {
+    compile_to_json(
+        compile_files_result(files, entry, json, options),
+        options.locale,
+        |file| file.and_then(|x| files.get(x)).map_or("", |x| x.as_str())
+    )
+}
+
+type CompileResult<'input> = (CompilerContext<'input>, String, HashSet<String>, Vec<Diagnostic>);
+
+fn compile_to_json<'a>(result: Result<CompileResult, Vec<Diagnostic>>, locale: Locale, source: impl Fn(Option<&str>) -> &'a str) -> String {
+    let localize = |diagnostics: Vec<Diagnostic>| diagnostics.into_iter().map(|x| x.localized(locale)).collect();
+    match result {
+        Ok((context, output, used_data, warnings)) =>
+            context.output_to_json(output, used_data, localize(warnings), source),
         Err(errors) =>
-            CompilerContext::errors_to_json(errors),
+            CompilerContext::errors_to_json(localize(errors), source),
+    }
+}
+
+fn context_errors(errors: Vec<String>) -> Vec<Diagnostic> {
+    errors.into_iter().map(|x| Diagnostic::error(ErrorCode::Internal, x)).collect()
+}
+
+fn compile_result<'input>(input: &'input str, json: &'input str, options: &CompileOptions) -> Result<CompileResult<'input>, Vec<Diagnostic>> {
+    let parser_context = ParserContext::new_with_json_and_options(json.to_owned(), options.clone()).map_err(context_errors)?;
+    let (parsed, mut errors) = parse_partial(&parser_context, input);
+    if let Some(parsed) = parsed {
+        errors.extend(analize(&parser_context, input, parsed).err().unwrap_or_default());
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let warnings = warnings(&parser_context, input);
+    Ok(generate(&parser_context, warnings))
+}
+
+fn compile_files_result<'input>(
+    files: &'input BTreeMap<String, String>,
+    entry: &str,
+    json: &'input str,
+    options: &CompileOptions,
+) -> Result<CompileResult<'input>, Vec<Diagnostic>> {
+    let parser_context = ParserContext::new_with_json_and_options(json.to_owned(), options.clone()).map_err(context_errors)?;
+    let (parsed, mut errors) = parse_files_partial(&parser_context, files, entry);
+    if let Some(parsed) = parsed {
+        errors.extend(analize_files(&parser_context, files, entry, parsed).err().unwrap_or_default());
+    }
+    if !errors.is_empty() {
+        return Err(errors);
     }
+    let warnings = warnings_files(&parser_context, files, entry);
+    Ok(generate(&parser_context, warnings))
 }
 
-fn compile_result<'input>(input: &'input str, json: &'input str) -> Result<(CompilerContext<'input>, String, HashSet<String>), Vec<String>> {
-    let parser_context = ParserContext::new_with_json(json.to_owned())?;
-    let parsed = parse(&parser_context, input)?;
-    analize(&parser_context, input, parsed)?;
-    
-    let compiler_context = CompilerContext::convert(&parser_context);
+fn generate<'input>(parser_context: &ParserContext<'input>, warnings: Vec<Diagnostic>) -> CompileResult<'input> {
+    let compiler_context = CompilerContext::convert(parser_context);
     let mut asm_container = AsmContainer::new();
     asm_container.push_data_part(generate_data_part(&compiler_context));
     asm_container.push_code_part(generate_code_part(&compiler_context));
     asm_container.prepare();
-    Ok((compiler_context, asm_container.to_string(), asm_container.used_data()))
+    (compiler_context, asm_container.to_string(), asm_container.used_data(), warnings)
 }
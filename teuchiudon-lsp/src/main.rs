@@ -1,4 +1,8 @@
 use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use teuchiudon_parser::context::Context;
+use teuchiudon_parser::{parse_partial, ErrorCode, Severity};
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
@@ -6,6 +10,61 @@ use tower_lsp::{Client, LanguageServer, LspService, Server};
 #[derive(Debug)]
 struct Backend {
     client: Client,
+    diagnostics: Mutex<HashMap<Url, Vec<Diagnostic>>>,
+}
+
+impl Backend {
+    async fn update_diagnostics(&self, uri: Url, text: &str, version: i32) {
+        let diagnostics = syntax_diagnostics(text);
+        self.diagnostics
+            .lock()
+            .unwrap()
+            .insert(uri.clone(), diagnostics.clone());
+        self.client
+            .publish_diagnostics(uri, diagnostics, Some(version))
+            .await;
+    }
+}
+
+/// Only syntax errors are reported, since semantic analysis needs the Udon symbols exported from Unity.
+fn syntax_diagnostics(text: &str) -> Vec<Diagnostic> {
+    let Ok(context) = Context::new() else {
+        return Vec::new();
+    };
+    let (_, errors) = parse_partial(&context, text);
+    errors
+        .into_iter()
+        .map(|x| Diagnostic {
+            range: x.range.map_or(Range::default(), |r| {
+                Range::new(
+                    Position::new(r.start.line as u32 - 1, r.start.utf16_column as u32 - 1),
+                    Position::new(r.end.line as u32 - 1, r.end.utf16_column as u32 - 1),
+                )
+            }),
+            severity: Some(match x.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            }),
+            code: Some(NumberOrString::String(x.code.to_string())),
+            source: Some("teuchiudon".to_string()),
+            message: x.message,
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn explanation_hover(diagnostic: &Diagnostic) -> Option<Hover> {
+    let Some(NumberOrString::String(code)) = &diagnostic.code else {
+        return None;
+    };
+    let code = code.parse::<ErrorCode>().ok()?;
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: code.explain().render(),
+        }),
+        range: Some(diagnostic.range),
+    })
 }
 
 #[tower_lsp::async_trait]
@@ -35,6 +94,7 @@ impl LanguageServer for Backend {
                     }),
                     file_operations: None,
                 }),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
                 ..ServerCapabilities::default()
             },
             ..Default::default()
@@ -83,16 +143,24 @@ impl LanguageServer for Backend {
         Ok(None)
     }
 
-    async fn did_open(&self, _: DidOpenTextDocumentParams) {
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
         self.client
             .log_message(MessageType::INFO, "file opened!")
             .await;
+        let document = params.text_document;
+        self.update_diagnostics(document.uri, &document.text, document.version)
+            .await;
     }
 
-    async fn did_change(&self, _: DidChangeTextDocumentParams) {
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
         self.client
             .log_message(MessageType::INFO, "file changed!")
             .await;
+        if let Some(change) = params.content_changes.pop() {
+            let document = params.text_document;
+            self.update_diagnostics(document.uri, &change.text, document.version)
+                .await;
+        }
     }
 
     async fn did_save(&self, _: DidSaveTextDocumentParams) {
@@ -101,10 +169,31 @@ impl LanguageServer for Backend {
             .await;
     }
 
-    async fn did_close(&self, _: DidCloseTextDocumentParams) {
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
         self.client
             .log_message(MessageType::INFO, "file closed!")
             .await;
+        self.diagnostics
+            .lock()
+            .unwrap()
+            .remove(&params.text_document.uri);
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params.position;
+        let uri = params.text_document_position_params.text_document.uri;
+        let hover = self
+            .diagnostics
+            .lock()
+            .unwrap()
+            .get(&uri)
+            .and_then(|diagnostics| {
+                diagnostics
+                    .iter()
+                    .filter(|x| x.range.start <= position && position <= x.range.end)
+                    .find_map(explanation_hover)
+            });
+        Ok(hover)
     }
 
     async fn completion(&self, _: CompletionParams) -> Result<Option<CompletionResponse>> {
@@ -122,6 +211,9 @@ impl LanguageServer for Backend {
 async fn main() {
     tracing_subscriber::fmt().init();
     let (stdin, stdout) = (tokio::io::stdin(), tokio::io::stdout());
-    let (service, socket) = LspService::new(|client| Backend { client });
+    let (service, socket) = LspService::new(|client| Backend {
+        client,
+        diagnostics: Mutex::new(HashMap::new()),
+    });
     Server::new(stdin, stdout, socket).serve(service).await;
 }
@@ -1,11 +1,17 @@
 use serde::Deserialize;
 use serde_json;
 use super::Context;
+use crate::error::diagnostic::ErrorCode;
 use crate::semantics::elements::{
     ElementError,
     base_ty::BaseTy,
     element::KeyElement,
     ev::Ev,
+    label::{
+        DataLabel,
+        DataLabelKind,
+    },
+    literal::Literal,
     method::{
         Method,
         MethodParamInOut,
@@ -20,6 +26,7 @@ use crate::semantics::elements::{
         TyInstance,
         TyLogicalKey,
     },
+    var::Var,
 };
 
 #[derive(Deserialize)]
@@ -27,6 +34,12 @@ struct UdonSymbols {
     base_tys: Vec<BaseTySymbol>,
     tys: Vec<TySymbol>,
     methods: Vec<MethodSymbol>,
+    #[serde(default)]
+    ctors: Vec<CtorSymbol>,
+    #[serde(default)]
+    fields: Vec<FieldSymbol>,
+    #[serde(default)]
+    consts: Vec<ConstSymbol>,
     evs: Vec<EvSymbol>,
 }
 
@@ -57,6 +70,31 @@ struct MethodSymbol {
     param_real_names: Vec<String>,
 }
 
+#[derive(Deserialize)]
+struct CtorSymbol {
+    ty: String,
+    param_tys: Vec<String>,
+    param_in_outs: Vec<String>,
+    real_name: String,
+    param_real_names: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct FieldSymbol {
+    ty: String,
+    name: String,
+    field_ty: String,
+    real_name: String,
+}
+
+#[derive(Deserialize)]
+struct ConstSymbol {
+    ty: String,
+    name: String,
+    const_ty: String,
+    value: String,
+}
+
 #[derive(Deserialize)]
 struct EvSymbol {
     name: String,
@@ -79,6 +117,12 @@ impl<'input> Context<'input> {
             .map_err(|e| vec![e.message])?;
         self.register_from_method_symbols(&symbols.methods)
             .map_err(|e| vec![e.message])?;
+        self.register_from_ctor_symbols(&symbols.ctors)
+            .map_err(|e| vec![e.message])?;
+        self.register_from_field_symbols(&symbols.fields)
+            .map_err(|e| vec![e.message])?;
+        self.register_from_const_symbols(&symbols.consts)
+            .map_err(|e| vec![e.message])?;
         self.register_from_ev_symbols(&symbols.evs)
             .map_err(|e| vec![e.message])?;
         Ok(())
@@ -131,7 +175,7 @@ impl<'input> Context<'input> {
                         "IN" => Ok(MethodParamInOut::In),
                         "IN_OUT" => Ok(MethodParamInOut::InOut),
                         "OUT" => Ok(MethodParamInOut::Out),
-                        _ => Err(ElementError::new("Illegal method param in/out kind".to_owned())),
+                        _ => Err(ElementError::new(ErrorCode::Internal, "Illegal method param in/out kind".to_owned())),
                     })
                     .collect::<Result<_, _>>()?,
                 sym.real_name.clone(),
@@ -141,6 +185,61 @@ impl<'input> Context<'input> {
         Ok(())
     }
 
+    fn register_from_ctor_symbols(&self, symbols: &Vec<CtorSymbol>) -> Result<(), ElementError> {
+        for sym in symbols {
+            Method::new(
+                self,
+                Ty::new_or_get_type_from_key(self, TyLogicalKey::new(sym.ty.clone()))?,
+                "new".to_owned(),
+                sym.param_tys.iter().map(|x| TyLogicalKey::new(x.to_owned()).get_value(self)).collect::<Result<_, _>>()?,
+                sym.param_in_outs.iter().map(|x|
+                    match x.as_str() {
+                        "IN" => Ok(MethodParamInOut::In),
+                        "IN_OUT" => Ok(MethodParamInOut::InOut),
+                        "OUT" => Ok(MethodParamInOut::Out),
+                        _ => Err(ElementError::new(ErrorCode::Internal, "Illegal method param in/out kind".to_owned())),
+                    })
+                    .collect::<Result<_, _>>()?,
+                sym.real_name.clone(),
+                sym.param_real_names.clone(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn register_from_field_symbols(&self, symbols: &Vec<FieldSymbol>) -> Result<(), ElementError> {
+        for sym in symbols {
+            Method::new(
+                self,
+                Ty::new_or_get_type_from_key(self, TyLogicalKey::new(sym.ty.clone()))?,
+                format!("get_{}", sym.name),
+                vec![TyLogicalKey::new(sym.field_ty.clone()).get_value(self)?],
+                vec![MethodParamInOut::Out],
+                sym.real_name.clone(),
+                vec!["__returnValue".to_owned()],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn register_from_const_symbols(&self, symbols: &Vec<ConstSymbol>) -> Result<(), ElementError> {
+        for sym in symbols {
+            let parent = TyLogicalKey::new(sym.ty.clone()).get_value(self)?;
+            let qual = parent.base.qual.new_or_get_pushed_qual(self, parent.base.name.clone());
+            let ty = TyLogicalKey::new(sym.const_ty.clone()).get_value(self)?;
+            let literal = Literal::new_or_get(self, sym.value.clone(), ty.clone())?;
+            Var::force_new(
+                self,
+                qual,
+                sym.name.clone(),
+                ty,
+                false,
+                Some(DataLabel::new(DataLabelKind::Literal(literal))),
+            );
+        }
+        Ok(())
+    }
+
     fn register_from_ev_symbols(&self, symbols: &Vec<EvSymbol>) -> Result<(), ElementError> {
         for sym in symbols {
             Ev::new(
@@ -152,7 +251,7 @@ impl<'input> Context<'input> {
                         "IN" => Ok(MethodParamInOut::In),
                         "IN_OUT" => Ok(MethodParamInOut::InOut),
                         "OUT" => Ok(MethodParamInOut::Out),
-                        _ => Err(ElementError::new("Illegal method param in/out kind".to_owned())),
+                        _ => Err(ElementError::new(ErrorCode::Internal, "Illegal method param in/out kind".to_owned())),
                     })
                     .collect::<Result<_, _>>()?,
                 sym.real_name.clone(),
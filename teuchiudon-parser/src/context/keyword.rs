@@ -6,6 +6,7 @@ use crate::lexer::ast::{
 
 pub struct KeywordContext {
     keywords: Vec<(&'static str, KeywordKind)>,
+    contextual_keywords: Vec<(&'static str, KeywordKind)>,
 }
 
 impl KeywordContext {
@@ -42,14 +43,18 @@ impl KeywordContext {
                 ("ty", KeywordKind::Ty),
                 ("use", KeywordKind::Use),
                 ("while", KeywordKind::While),
-            ]
+            ],
+            contextual_keywords: vec![
+                ("custom", KeywordKind::Custom),
+            ],
         }
     }
 
     pub fn from_str<'input>(&self, name: &str, slice: &'input str) -> Option<Rc<Keyword<'input>>> {
-        self.keywords.iter().find(|x| x.0 == name).map(|x| Rc::new(Keyword { slice, kind: x.1 }))
+        self.keywords.iter().chain(self.contextual_keywords.iter()).find(|x| x.0 == name).map(|x| Rc::new(Keyword { slice, kind: x.1 }))
     }
 
+    /// Reserved keywords only; contextual keywords such as `custom` remain usable as identifiers.
     pub fn iter_keyword_str(&self) -> impl Iterator<Item = &str> {
         self.keywords.iter().map(|x| x.0)
     }
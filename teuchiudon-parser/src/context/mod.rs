@@ -1,6 +1,7 @@
 pub mod id_factory;
 pub mod keyword;
 pub mod op_code;
+pub mod options;
 pub mod qual_stack;
 pub mod semantic_op;
 pub mod semantic_ty_op;
@@ -12,62 +13,84 @@ use self::{
     id_factory::IdFactory,
     keyword::KeywordContext,
     op_code::OpCodeContext,
+    options::Options,
     qual_stack::QualStack,
     semantic_op::SemanticOpContext,
     semantic_ty_op::SemanticTyOpContext,
     store::Store,
     vec_store::VecStore,
 };
-use crate::semantics::elements::{
-    base_ty::{
-        BaseTy,
-        BaseTyKey,
-        BaseTyLogicalKey,
+use std::{
+    cell::RefCell,
+    collections::{
+        HashMap,
+        HashSet,
     },
-    element::SemanticElement,
-    ev::{
-        Ev,
-        EvKey,
-    },
-    ev_stats::EvStats,
-    eval_fn::{
-        EvalFn,
-        EvalFnKey,
-    },
-    fn_stats::{
-        FnKey,
-        FnStats,
-    },
-    literal::{
-        Literal,
-        LiteralKey,
-    },
-    method::{
-        Method,
-        MethodKey,
-    },
-    named_methods::{
-        NamedMethods,
-        NamedMethodsKey,
-    },
-    qual::{
-        Qual,
-        QualKey,
-    },
-    top_stat::TopStat,
-    ty::{
-        Ty,
-        TyKey,
-        TyLogicalKey,
-    },
-    valued_var::ValuedVar,
-    var::{
-        Var,
-        VarKey,
+    rc::Rc,
+};
+use crate::error::{
+    ErrorTree,
+    diagnostic::ErrorCode,
+};
+use crate::parser;
+use crate::semantics::{
+    SemanticError,
+    elements::{
+        base_ty::{
+            BaseTy,
+            BaseTyKey,
+            BaseTyLogicalKey,
+        },
+        element::SemanticElement,
+        ev::{
+            Ev,
+            EvKey,
+        },
+        ev_stats::EvStats,
+        eval_fn::{
+            EvalFn,
+            EvalFnKey,
+        },
+        fn_stats::{
+            FnKey,
+            FnStats,
+        },
+        literal::{
+            Literal,
+            LiteralKey,
+        },
+        method::{
+            Method,
+            MethodKey,
+        },
+        named_methods::{
+            NamedMethods,
+            NamedMethodsKey,
+        },
+        qual::{
+            Qual,
+            QualKey,
+        },
+        top_stat::TopStat,
+        ty::{
+            Ty,
+            TyKey,
+            TyLogicalKey,
+        },
+        ty_alias::{
+            TyAlias,
+            TyAliasKey,
+        },
+        valued_var::ValuedVar,
+        var::{
+            Var,
+            VarKey,
+        },
     },
 };
 
 pub struct Context<'input> {
+    pub options: Options,
     pub keyword: KeywordContext,
     pub op_code: OpCodeContext,
     pub semantic_op: SemanticOpContext,
@@ -76,11 +99,13 @@ pub struct Context<'input> {
     pub loop_id_factory: IdFactory,
     pub let_in_id_factory: IdFactory,
     pub qual_stack: QualStack,
+    pub non_null_vars: RefCell<Vec<Rc<Var>>>,
     pub qual_store: Store<QualKey, Qual>,
     pub base_ty_store: Store<BaseTyKey, BaseTy>,
     pub base_ty_logical_store: Store<BaseTyLogicalKey, BaseTy>,
     pub ty_store: Store<TyKey, Ty>,
     pub ty_logical_store: Store<TyLogicalKey, Ty>,
+    pub ty_alias_store: Store<TyAliasKey, TyAlias<'input>>,
     pub ev_store: Store<EvKey, Ev>,
     pub literal_store: Store<LiteralKey, Literal>,
     pub method_store: Store<MethodKey, Method>,
@@ -91,11 +116,23 @@ pub struct Context<'input> {
     pub fn_stats_store: Store<FnKey, FnStats<'input>>,
     pub eval_fn_store: Store<EvalFnKey, EvalFn<'input>>,
     pub valued_var_store: Store<VarKey, ValuedVar>,
+    pub warning_store: VecStore<SemanticError<'input>>,
+    pub mod_files: RefCell<HashMap<String, Rc<parser::ast::Target<'input>>>>,
+    pub(crate) mod_paths: RefCell<HashMap<String, &'input str>>,
+    pub(crate) parse_errors: RefCell<Vec<ErrorTree<'input>>>,
+    pub(crate) stashed_parse_error: RefCell<Option<ErrorTree<'input>>>,
+    pub(crate) semantic_errors: RefCell<Vec<SemanticError<'input>>>,
+    pub(crate) poisoned_vars: RefCell<Vec<Rc<Var>>>,
+    pub(crate) poison_touched: RefCell<bool>,
+    pub(crate) used_var_ids: RefCell<HashSet<usize>>,
+    pub(crate) bindings: RefCell<Vec<(Rc<Var>, &'input str, ErrorCode)>>,
+    pub(crate) top_level_bindings: RefCell<Vec<(Rc<Var>, &'input str)>>,
 }
 
 impl<'input> Context<'input> {
     pub fn new() -> Result<Self, Vec<String>> {
         let context = Self {
+            options: Options::default(),
             keyword: KeywordContext::new(),
             op_code: OpCodeContext::new(),
             semantic_op: SemanticOpContext::new(),
@@ -104,11 +141,13 @@ impl<'input> Context<'input> {
             loop_id_factory: IdFactory::new(),
             let_in_id_factory: IdFactory::new(),
             qual_stack: QualStack::new(),
+            non_null_vars: RefCell::new(Vec::new()),
             qual_store: Store::new(|x| format!("Specified qualifier `{}` not found", x.description())),
             base_ty_store: Store::new(|x| format!("Specified type `{}` not found", x.description())),
             base_ty_logical_store: Store::new(|x| format!("Specified type `{}` not found", x.description())),
             ty_store: Store::new(|x| format!("Specified type `{}` not found", x.description())),
             ty_logical_store: Store::new(|x| format!("Specified type `{}` not found", x.description())),
+            ty_alias_store: Store::new(|x| format!("Specified type alias `{}` not found", x.description())),
             ev_store: Store::new(|x| format!("Specified event `{}` not found", x.description())),
             literal_store: Store::new(|x| format!("Specified literal `{}` not found", x.description())),
             method_store: Store::new(|x| format!("Specified method `{}` not found", x.description())),
@@ -119,13 +158,29 @@ impl<'input> Context<'input> {
             fn_stats_store: Store::new(|x| format!("Specified function `{}` not found", x.description())),
             eval_fn_store: Store::new(|x| format!("Specified function evaluation `{}` not found", x.description())),
             valued_var_store: Store::new(|x| format!("Specified variable `{}` not found", x.description())),
+            warning_store: VecStore::new(),
+            mod_files: RefCell::new(HashMap::new()),
+            mod_paths: RefCell::new(HashMap::new()),
+            parse_errors: RefCell::new(Vec::new()),
+            stashed_parse_error: RefCell::new(None),
+            semantic_errors: RefCell::new(Vec::new()),
+            poisoned_vars: RefCell::new(Vec::new()),
+            poison_touched: RefCell::new(false),
+            used_var_ids: RefCell::new(HashSet::new()),
+            bindings: RefCell::new(Vec::new()),
+            top_level_bindings: RefCell::new(Vec::new()),
         };
         context.register_default_tys()?;
         Ok(context)
     }
 
     pub fn new_with_json(json: String) -> Result<Self, Vec<String>> {
-        let context = Self::new()?;
+        Self::new_with_json_and_options(json, Options::default())
+    }
+
+    pub fn new_with_json_and_options(json: String, options: Options) -> Result<Self, Vec<String>> {
+        let mut context = Self::new()?;
+        context.options = options;
         context.register_from_json(json)?;
         context.register_named_methods()?;
         Ok(context)
@@ -36,8 +36,10 @@ impl OpCodeContext {
                 ("<", OpCodeKind::Lt),
                 (">", OpCodeKind::Gt),
                 ("_", OpCodeKind::Wildcard),
+                ("?", OpCodeKind::Question),
                 ("<-", OpCodeKind::Iter),
                 ("->", OpCodeKind::Arrow),
+                ("=>", OpCodeKind::FatArrow),
                 ("::", OpCodeKind::DoubleColon),
                 ("??", OpCodeKind::Coalescing),
                 ("?.", OpCodeKind::CoalescingAccess),
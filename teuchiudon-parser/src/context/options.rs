@@ -0,0 +1,11 @@
+use crate::error::{
+    catalog::Locale,
+    diagnostic::ErrorCode,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub null_safety: bool,
+    pub suppressed_warnings: Vec<ErrorCode>,
+    pub locale: Locale,
+}
@@ -1,6 +1,12 @@
-use std::cell::RefCell;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+};
 use crate::context::Context;
+use crate::error::diagnostic::ErrorCode;
 use crate::semantics::elements::{
+    ElementError,
+    element::SemanticElement,
     qual::{
         Qual,
         QualKey,
@@ -10,12 +16,14 @@ use crate::semantics::elements::{
 
 pub struct QualStack {
     stack: RefCell<Vec<QualKey>>,
+    globs: RefCell<HashMap<QualKey, Vec<QualKey>>>,
 }
 
 impl QualStack {
     pub fn new() -> Self {
         Self {
             stack: RefCell::new(vec![QualKey::top()]),
+            globs: RefCell::new(HashMap::new()),
         }
     }
 
@@ -38,17 +46,44 @@ impl QualStack {
         qual.unwrap()
     }
 
+    pub fn depth(&self) -> usize {
+        self.stack.borrow().len()
+    }
+
+    pub fn truncate(&self, depth: usize) {
+        self.stack.borrow_mut().truncate(depth.max(1));
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = QualKey> {
         self.stack.borrow().iter().rev().cloned().collect::<Vec<_>>().into_iter()
     }
 
-    pub fn find_ok<O, E>(&self, f: impl Fn(QualKey) -> Result<O, E>) -> Option<O> {
+    pub fn add_glob(&self, qual: QualKey, target: QualKey) {
+        self.globs.borrow_mut().entry(qual).or_default().push(target);
+    }
+
+    pub fn find_ok<O, E>(&self, f: impl Fn(QualKey) -> Result<O, E>) -> Result<Option<O>, ElementError> {
         for qual in self.iter() {
-            match f(qual) {
-                Ok(x) => return Some(x),
-                Err(_) => (),
+            if let Ok(x) = f(qual.clone()) {
+                return Ok(Some(x));
+            }
+
+            let globs = self.globs.borrow().get(&qual).cloned().unwrap_or_default();
+            let mut found =
+                globs.into_iter()
+                .filter_map(|x| f(x.clone()).ok().map(|o| (x, o)))
+                .collect::<Vec<_>>();
+            if found.len() >= 2 {
+                return Err(ElementError::new(ErrorCode::Ambiguous, format!(
+                    "Specified name is ambiguous between `{}::*` and `{}::*`",
+                    found[0].0.description(),
+                    found[1].0.description()
+                )));
+            }
+            if let Some((_, x)) = found.pop() {
+                return Ok(Some(x));
             }
         }
-        None
+        Ok(None)
     }
 }
@@ -12,10 +12,7 @@ impl SemanticOpContext {
         Self {
             priorities: vec![
                 (Box::new(|op_code: &Op| *op_code == Op::TyAccess),Assoc::Left),
-                (Box::new(|op_code: &Op| *op_code == Op::Access || *op_code == Op::CoalescingAccess),Assoc::Left),
-                (Box::new(|op_code: &Op| *op_code == Op::EvalFn),Assoc::Left),
-                (Box::new(|op_code: &Op| *op_code == Op::EvalSpreadFn),Assoc::Left),
-                (Box::new(|op_code: &Op| *op_code == Op::EvalKey),Assoc::Left),
+                (Box::new(|op_code: &Op| *op_code == Op::Access || *op_code == Op::CoalescingAccess || *op_code == Op::EvalFn || *op_code == Op::EvalSpreadFn || *op_code == Op::EvalKey),Assoc::Left),
                 (Box::new(|op_code: &Op| *op_code == Op::CastOp),Assoc::Left),
                 (Box::new(|op_code: &Op| *op_code == Op::Mul || *op_code == Op::Div || *op_code == Op::Mod),Assoc::Left),
                 (Box::new(|op_code: &Op| *op_code == Op::Add || *op_code == Op::Sub),Assoc::Left),
@@ -11,7 +11,8 @@ impl SemanticTyOpContext {
     pub fn new() -> Self {
         Self {
             priorities: vec![
-                (Box::new(|op_code: &TyOp| *op_code == TyOp::Access), Assoc::Left),
+                (Box::new(|op_code: &TyOp| *op_code == TyOp::Access || *op_code == TyOp::Apply), Assoc::Left),
+                (Box::new(|op_code: &TyOp| *op_code == TyOp::Nullable), Assoc::Left),
             ],
         }
     }
@@ -7,6 +7,7 @@ use std::{
     hash::Hash,
     rc::Rc,
 };
+use crate::error::diagnostic::ErrorCode;
 use crate::semantics::elements::{
     ElementError,
     element::SemanticElement,
@@ -41,7 +42,7 @@ where
         let id = self.next_id();
         let mut id_map = self.id_map.borrow_mut();
         if id_map.contains_key(&key) {
-            return Err(ElementError::new(format!("Registration duplicated: `{}`", key.description())));
+            return Err(ElementError::new(ErrorCode::Duplicated, format!("Registration duplicated: `{}`", key.description())));
         }
         id_map.insert(key, id);
         self.values.borrow_mut().push(value);
@@ -56,7 +57,7 @@ where
     }
 
     pub fn get(&self, key: &Key) -> Result<Rc<Value>, ElementError> {
-        self.id_map.borrow().get(key).map(|x| self.values.borrow()[*x].clone()).ok_or(ElementError::new((self.not_found)(key)))
+        self.id_map.borrow().get(key).map(|x| self.values.borrow()[*x].clone()).ok_or(ElementError::new(ErrorCode::NotFound, (self.not_found)(key)))
     }
 
     pub fn values(&self) -> impl Iterator<Item = Rc<Value>> {
@@ -22,6 +22,7 @@ impl<'input> Context<'input> {
             ("unit", "unit", None, true, Vec::new()),
             ("tuple", "tuple", None, false, Vec::new()),
             ("array", "array", None, false, Vec::new()),
+            ("nullable", "nullable", None, false, Vec::new()),
             ("function", "function", None, false, Vec::new()),
             ("nfunction", "nfunction", None, false, Vec::new()),
             ("closure", "closure", None, false, Vec::new()),
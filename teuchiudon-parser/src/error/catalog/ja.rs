@@ -0,0 +1,199 @@
+use super::{
+    Catalog,
+    ErrorCode,
+};
+
+pub(super) static CATALOG: Catalog = Catalog {
+    messages: &[
+        (ErrorCode::Internal, &[
+            ("Illegal method param in/out kind", "メソッド引数の in/out の種別が不正です"),
+            ("Udon symbols cannot be initialized", "Udon シンボルを初期化できません"),
+        ]),
+        (ErrorCode::Syntax, &[
+            ("Parse error", "構文エラー"),
+            ("Parse error, expected {}, found {}", "構文エラー、{} が必要ですが {} が見つかりました"),
+            ("Parse error, found {}", "構文エラー、{} は使用できません"),
+            ("Statement has syntax errors", "文に構文エラーがあります"),
+            ("Add `;` after the closing `}` of the block", "ブロックを閉じる `}` の後に `;` を追加してください"),
+            ("Add `;` at the end of the previous line", "前の行の末尾に `;` を追加してください"),
+        ]),
+        (ErrorCode::NotFound, &[
+            ("Specified qualifier `{}` not found", "指定された修飾子 `{}` が見つかりません"),
+            ("Specified type `{}` not found", "指定された型 `{}` が見つかりません"),
+            ("Specified type alias `{}` not found", "指定された型エイリアス `{}` が見つかりません"),
+            ("Specified event `{}` not found", "指定されたイベント `{}` が見つかりません"),
+            ("Specified literal `{}` not found", "指定されたリテラル `{}` が見つかりません"),
+            ("Specified method `{}` not found", "指定されたメソッド `{}` が見つかりません"),
+            ("Specified variable `{}` not found", "指定された変数 `{}` が見つかりません"),
+            ("Specified function `{}` not found", "指定された関数 `{}` が見つかりません"),
+            ("Specified function evaluation `{}` not found", "指定された関数呼び出し `{}` が見つかりません"),
+            ("Specified item `{}` not found", "指定された項目 `{}` が見つかりません"),
+            ("Method `{}` not found in `{}`", "メソッド `{}` が `{}` に見つかりません"),
+            ("Module file `{}` not found", "モジュールファイル `{}` が見つかりません"),
+            ("Constructor of type `{}` not found", "型 `{}` のコンストラクタが見つかりません"),
+            ("Conversion from `{}` to `{}` not found", "`{}` から `{}` への変換が見つかりません"),
+        ]),
+        (ErrorCode::Duplicated, &[
+            ("Registration duplicated: `{}`", "`{}` が重複して登録されています"),
+            ("Enum member `{}` is duplicated", "列挙型のメンバー `{}` が重複しています"),
+            ("Module `{}` is duplicated", "モジュール `{}` が重複しています"),
+            ("Type parameter `{}` is duplicated", "型パラメータ `{}` が重複しています"),
+            ("First defined here", "最初の定義はここです"),
+        ]),
+        (ErrorCode::Ambiguous, &[
+            ("Specified name is ambiguous between `{}::*` and `{}::*`", "指定された名前は `{}::*` と `{}::*` のどちらを指すか曖昧です"),
+            ("Imported name `{}` is ambiguous with an existing item", "インポートした名前 `{}` が既存の項目と曖昧です"),
+        ]),
+        (ErrorCode::TypeMismatch, &[
+            ("Cannot be inferred type `{}` from `{}`", "`{1}` から型 `{0}` を推論できません"),
+            ("No common type found for `{}`", "`{}` に共通する型が見つかりません"),
+            ("Type `{}` cannot be wrapped", "型 `{}` はラップできません"),
+            ("Condition of type `{}` is not `bool`", "条件の型 `{}` が `bool` ではありません"),
+            ("Branch of `if` has type `{}`, expected `{}`", "`if` の分岐の型が `{}` ですが、`{}` が必要です"),
+            ("Match arm has type `{}`, expected `{}`", "マッチアームの型が `{}` ですが、`{}` が必要です"),
+            ("Right side of `??` has type `{}`, expected `{}`", "`??` の右辺の型が `{}` ですが、`{}` が必要です"),
+            ("Cannot cast `{}` to `{}`", "`{}` を `{}` にキャストできません"),
+            ("Value of type `{}` cannot be assigned to variable `{}` of type `{}`", "型 `{0}` の値は型 `{2}` の変数 `{1}` に代入できません"),
+        ]),
+        (ErrorCode::InferenceFailed, &[
+            ("Type inference not succeeded", "型推論に失敗しました"),
+        ]),
+        (ErrorCode::NotAType, &[
+            ("Specified expression is not a type", "指定された式は型ではありません"),
+            ("Type argument must be a type", "型引数は型でなければなりません"),
+            ("Left side of type application is not a type", "型適用の左辺が型ではありません"),
+            ("Left side of `?` is not a type", "`?` の左辺が型ではありません"),
+            ("Right side of `as` is not a type", "`as` の右辺が型ではありません"),
+        ]),
+        (ErrorCode::InvalidTypeArgument, &[
+            ("Type alias `{}` requires {} type arguments", "型エイリアス `{}` には {} 個の型引数が必要です"),
+            ("Type `{}` cannot be a type argument", "型 `{}` は型引数にできません"),
+            ("Type `{}` cannot take type arguments", "型 `{}` は型引数を取れません"),
+            ("Type arguments can only be passed to methods", "型引数はメソッドにのみ渡せます"),
+            ("Type argument `{}` is not compatible with the result type `{}` of method `{}`", "型引数 `{0}` はメソッド `{2}` の戻り値の型 `{1}` と互換性がありません"),
+            ("Methods take at most one type argument, but {} were given", "メソッドの型引数は 1 個までですが、{} 個指定されています"),
+            ("Method `{}` has no result type to apply a type argument to", "メソッド `{}` には型引数を適用する戻り値の型がありません"),
+        ]),
+        (ErrorCode::InvalidNullable, &[
+            ("Type `{}` is already nullable", "型 `{}` はすでに null 許容です"),
+            ("Type `{}` is not a reference type and cannot be nullable", "型 `{}` は参照型ではないため null 許容にできません"),
+        ]),
+        (ErrorCode::InvalidOperand, &[
+            ("Illegal use of type access op `::`", "型アクセス演算子 `::` の使い方が不正です"),
+            ("Illegal use of access op `.`", "アクセス演算子 `.` の使い方が不正です"),
+            ("Right side of `::` is not a term", "`::` の右辺が項ではありません"),
+            ("Right side of `::` cannot be evaluated", "`::` の右辺を評価できません"),
+            ("Left side of `::` is not a qualifier or a type", "`::` の左辺が修飾子でも型でもありません"),
+            ("Right side of type application is not a term", "型適用の右辺が項ではありません"),
+            ("Right side of type application cannot apply", "型適用の右辺に適用できません"),
+            ("Right side of `.` is not a term", "`.` の右辺が項ではありません"),
+            ("Right side of `.` cannot be evaluated", "`.` の右辺を評価できません"),
+            ("Value of type `{}` cannot be a receiver", "型 `{}` の値はレシーバにできません"),
+            ("Right side of `eval fn` is not a term", "`eval fn` の右辺が項ではありません"),
+            ("Right side of `eval fn` cannot apply", "`eval fn` の右辺に適用できません"),
+            ("Left side of `eval fn` is not a function or a method", "`eval fn` の左辺が関数でもメソッドでもありません"),
+            ("Operator `{}` is not defined for type `{}`", "演算子 `{}` は型 `{}` に対して定義されていません"),
+            ("Operator `{}` is not supported yet", "演算子 `{}` はまだサポートされていません"),
+            ("Left side of `=` is not a variable", "`=` の左辺が変数ではありません"),
+            ("Variable `{}` is not mutable", "変数 `{}` は変更可能ではありません"),
+            ("Declare it with `let mut` to assign to it", "代入するには `let mut` で宣言してください"),
+            ("Left side of `??` has non-nullable type `{}`", "`??` の左辺が null 非許容の型 `{}` です"),
+        ]),
+        (ErrorCode::NoCompatibleMethod, &[
+            ("No compatible methods found", "互換性のあるメソッドが見つかりません"),
+            ("Too many compatible methods found", "互換性のあるメソッドが複数見つかりました"),
+        ]),
+        (ErrorCode::InvalidArgument, &[
+            ("Function arguments cannot be tuple", "関数の引数にタプルは使えません"),
+            ("Argument for in/out parameter `{}` must be marked `mut`", "in/out 引数 `{}` に渡す値には `mut` を付けてください"),
+            ("Argument for in/out parameter `{}` must be a mutable variable", "in/out 引数 `{}` に渡す値は可変の変数でなければなりません"),
+            ("Argument for in parameter `{}` must not be marked `mut`", "in 引数 `{}` に渡す値には `mut` を付けられません"),
+        ]),
+        (ErrorCode::PossiblyNull, &[
+            ("Value of type `{}` may be null, check it against `null` before use", "型 `{}` の値は null の可能性があります。使用前に `null` と比較してください"),
+            ("Argument of type `{}` may be null, check it against `null` before use", "型 `{}` の引数は null の可能性があります。使用前に `null` と比較してください"),
+            ("Value of type `{}` may be null and cannot be cast to `{}`", "型 `{}` の値は null の可能性があるため `{}` にキャストできません"),
+            ("Check it with `if x != null` first, or give a default value with `??`", "先に `if x != null` で確認するか、`??` で既定値を指定してください"),
+        ]),
+        (ErrorCode::InvalidPattern, &[
+            ("Cannot match a value of type `{}`", "型 `{}` の値はマッチできません"),
+            ("Pattern of type `{}` cannot match a value of type `{}`", "型 `{}` のパターンは型 `{}` の値にマッチできません"),
+            ("Range pattern `{}..{}` matches no value", "範囲パターン `{}..{}` にマッチする値がありません"),
+            ("Tuple pattern cannot match a value of type `{}`", "タプルパターンは型 `{}` の値にマッチできません"),
+            ("Tuple pattern has {} elements, expected {}", "タプルパターンの要素数が {} 個ですが、{} 個必要です"),
+            ("Pattern `{}` is not a constant", "パターン `{}` は定数ではありません"),
+            ("Cannot compare a value of type `{}` in a pattern", "型 `{}` の値はパターンで比較できません"),
+        ]),
+        (ErrorCode::InvalidPublicVar, &[
+            ("Public variable must not be tuple", "公開変数にタプルは使えません"),
+            ("Public variable should be assigned from a literal", "公開変数にはリテラルを代入してください"),
+        ]),
+        (ErrorCode::NotStorable, &[
+            ("Result of `if` of type `{}` cannot be stored", "型 `{}` の `if` の結果は保持できません"),
+            ("Match result of type `{}` cannot be stored", "型 `{}` のマッチの結果は保持できません"),
+            ("Result of method `{}` cannot be stored", "メソッド `{}` の結果は保持できません"),
+        ]),
+        (ErrorCode::OutOfRange, &[
+            ("Literal `{}` is out of range for type `{}`, allowed range is {}", "リテラル `{}` は型 `{}` の範囲外です。使用できる範囲は {} です"),
+        ]),
+        (ErrorCode::InvalidEvent, &[
+            ("Function `{}` is not a Udon event", "関数 `{}` は Udon のイベントではありません"),
+            ("Did you mean `{}`? Add `custom` after `pub` to declare a custom event", "`{}` の誤りではありませんか？カスタムイベントを宣言するには `pub` の後に `custom` を追加してください"),
+            ("Add `custom` after `pub` to declare a custom event", "カスタムイベントを宣言するには `pub` の後に `custom` を追加してください"),
+            ("Function `{}` is a Udon event and cannot be marked `custom`", "関数 `{}` は Udon のイベントのため `custom` を付けられません"),
+            ("Only public functions can be custom events", "カスタムイベントにできるのは公開関数のみです"),
+        ]),
+        (ErrorCode::InvalidImport, &[
+            ("Entry file `{}` not found", "エントリファイル `{}` が見つかりません"),
+            ("Imported item must be qualified", "インポートする項目は修飾されていなければなりません"),
+        ]),
+        (ErrorCode::NonExhaustiveMatch, &[
+            ("Match is not exhaustive", "マッチが網羅的ではありません"),
+        ]),
+        (ErrorCode::UnusedVariable, &[
+            ("Variable `{}` is never used", "変数 `{}` は使用されていません"),
+        ]),
+        (ErrorCode::UnusedFunction, &[
+            ("Function `{}` is never used", "関数 `{}` は使用されていません"),
+        ]),
+        (ErrorCode::UnusedPublicVar, &[
+            ("Public variable `{}` is never read", "公開変数 `{}` は読み取られていません"),
+        ]),
+        (ErrorCode::ShadowedTopLevel, &[
+            ("Variable `{}` shadows a top-level binding", "変数 `{}` がトップレベルの束縛を隠しています"),
+            ("Top-level binding defined here", "トップレベルの束縛はここで定義されています"),
+        ]),
+        (ErrorCode::UnreachableCode, &[
+            ("Unreachable code", "到達しないコードです"),
+        ]),
+        (ErrorCode::DiscardedValue, &[
+            ("Value of type `{}` is discarded", "型 `{}` の値が破棄されています"),
+        ]),
+        (ErrorCode::PossiblyUninitialized, &[
+            ("Variable `{}` may be read before it is initialized", "変数 `{}` は初期化される前に読み取られる可能性があります"),
+            ("Initialized in `_start`, which `{}` may precede", "`_start` で初期化されますが、`{}` はそれより先に実行される可能性があります"),
+        ]),
+        (ErrorCode::UnusedModFile, &[
+            ("Module file `{}` is never declared", "モジュールファイル `{}` はどこでも宣言されていません"),
+            ("Declare it with `mod {};` in its parent module to compile it", "コンパイルするには親モジュールで `mod {};` と宣言してください"),
+        ]),
+    ],
+    terms: &[
+        ("one of ", "次のいずれか: "),
+        (" or ", " または "),
+        (" to ", " ～ "),
+        ("end of file", "ファイルの終端"),
+        ("statement", "文"),
+        ("expression", "式"),
+        ("type", "型"),
+        ("block", "ブロック"),
+        ("pattern", "パターン"),
+        ("match arm", "マッチアーム"),
+        ("argument", "引数"),
+        ("identifier", "識別子"),
+        ("number", "数値"),
+        ("character", "文字"),
+        ("string", "文字列"),
+        ("boolean", "真偽値"),
+    ],
+};
@@ -0,0 +1,125 @@
+mod ja;
+
+use std::{
+    fmt,
+    str::FromStr,
+};
+use super::diagnostic::ErrorCode;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Locale {
+    #[default]
+    En,
+    Ja,
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [
+        Self::En,
+        Self::Ja,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Ja => "ja",
+        }
+    }
+
+    fn catalog(&self) -> Option<&'static Catalog> {
+        match self {
+            Self::En => None,
+            Self::Ja => Some(&ja::CATALOG),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+        .find(|x| x.as_str() == s)
+        .ok_or(format!("Unknown locale `{}`", s))
+    }
+}
+
+/// Translations of the English messages, where `{}` in a template stands for the next argument and `{N}` for the N-th one.
+/// `messages` is keyed by the diagnostic code, and `terms` translates the words outside backquotes in the arguments.
+pub(crate) struct Catalog {
+    pub messages: &'static [(ErrorCode, &'static [(&'static str, &'static str)])],
+    pub terms: &'static [(&'static str, &'static str)],
+}
+
+/// Translates an English message of the diagnostic `code`, or returns it as is if no translation is found.
+pub fn translate(locale: Locale, code: ErrorCode, message: &str) -> String {
+    let Some(catalog) = locale.catalog()
+        else {
+            return message.to_owned();
+        };
+    catalog.messages.iter()
+    .filter(|(c, _)| *c == code)
+    .flat_map(|(_, templates)| templates.iter())
+    .find_map(|(en, translated)| {
+        let args = match_template(en, message)?;
+        Some(fill_template(translated, args.into_iter().map(|x| translate_terms(catalog, x)).collect()))
+    })
+    .unwrap_or(message.to_owned())
+}
+
+fn match_template<'message>(template: &str, message: &'message str) -> Option<Vec<&'message str>> {
+    let mut parts = template.split("{}");
+    let mut rest = message.strip_prefix(parts.next()?)?;
+    let mut args = Vec::new();
+    for part in parts {
+        let end = if part.is_empty() { rest.len() } else { rest.find(part)? };
+        args.push(&rest[..end]);
+        rest = &rest[end + part.len()..];
+    }
+    rest.is_empty().then_some(args)
+}
+
+fn fill_template(template: &str, args: Vec<String>) -> String {
+    let mut filled = String::new();
+    let mut rest = template;
+    let mut next = 0;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}')
+            else {
+                break;
+            };
+        let index = match rest[start + 1..start + len].parse::<usize>() {
+            Ok(x) => x,
+            Err(_) => {
+                next += 1;
+                next - 1
+            },
+        };
+        filled.push_str(&rest[..start]);
+        filled.push_str(args.get(index).map_or("", |x| x.as_str()));
+        rest = &rest[start + len + 1..];
+    }
+    filled.push_str(rest);
+    filled
+}
+
+fn translate_terms(catalog: &Catalog, arg: &str) -> String {
+    arg.split('`')
+    .enumerate()
+    .map(|(i, x)|
+        if i % 2 == 0 {
+            catalog.terms.iter().fold(x.to_owned(), |acc, (en, translated)| acc.replace(en, translated))
+        }
+        else {
+            x.to_owned()
+        }
+    )
+    .collect::<Vec<_>>()
+    .join("`")
+}
@@ -0,0 +1,283 @@
+use std::{
+    fmt,
+    str::FromStr,
+};
+use serde::{
+    Serialize,
+    Serializer,
+};
+use super::{
+    NEWLINE,
+    catalog::{
+        Locale,
+        translate,
+    },
+    char_caret,
+    line_head,
+    line_slice,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorCode {
+    Internal,
+    Syntax,
+    NotFound,
+    Duplicated,
+    Ambiguous,
+    TypeMismatch,
+    InferenceFailed,
+    NotAType,
+    InvalidTypeArgument,
+    InvalidNullable,
+    InvalidOperand,
+    NoCompatibleMethod,
+    InvalidArgument,
+    PossiblyNull,
+    InvalidPattern,
+    InvalidPublicVar,
+    NotStorable,
+    OutOfRange,
+    InvalidEvent,
+    InvalidImport,
+    NonExhaustiveMatch,
+    UnusedVariable,
+    UnusedFunction,
+    UnusedPublicVar,
+    ShadowedTopLevel,
+    UnreachableCode,
+    DiscardedValue,
+    PossiblyUninitialized,
+    UnusedModFile,
+}
+
+impl ErrorCode {
+    pub const ALL: [Self; 29] = [
+        Self::Internal,
+        Self::Syntax,
+        Self::NotFound,
+        Self::Duplicated,
+        Self::Ambiguous,
+        Self::TypeMismatch,
+        Self::InferenceFailed,
+        Self::NotAType,
+        Self::InvalidTypeArgument,
+        Self::InvalidNullable,
+        Self::InvalidOperand,
+        Self::NoCompatibleMethod,
+        Self::InvalidArgument,
+        Self::PossiblyNull,
+        Self::InvalidPattern,
+        Self::InvalidPublicVar,
+        Self::NotStorable,
+        Self::OutOfRange,
+        Self::InvalidEvent,
+        Self::InvalidImport,
+        Self::NonExhaustiveMatch,
+        Self::UnusedVariable,
+        Self::UnusedFunction,
+        Self::UnusedPublicVar,
+        Self::ShadowedTopLevel,
+        Self::UnreachableCode,
+        Self::DiscardedValue,
+        Self::PossiblyUninitialized,
+        Self::UnusedModFile,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Internal => "E0000",
+            Self::Syntax => "E0001",
+            Self::NotFound => "E0100",
+            Self::Duplicated => "E0101",
+            Self::Ambiguous => "E0102",
+            Self::TypeMismatch => "E0200",
+            Self::InferenceFailed => "E0201",
+            Self::NotAType => "E0202",
+            Self::InvalidTypeArgument => "E0203",
+            Self::InvalidNullable => "E0204",
+            Self::InvalidOperand => "E0300",
+            Self::NoCompatibleMethod => "E0301",
+            Self::InvalidArgument => "E0302",
+            Self::PossiblyNull => "E0303",
+            Self::InvalidPattern => "E0400",
+            Self::InvalidPublicVar => "E0500",
+            Self::NotStorable => "E0501",
+            Self::OutOfRange => "E0502",
+            Self::InvalidEvent => "E0503",
+            Self::InvalidImport => "E0600",
+            Self::NonExhaustiveMatch => "W0001",
+            Self::UnusedVariable => "W0002",
+            Self::UnusedFunction => "W0003",
+            Self::UnusedPublicVar => "W0004",
+            Self::ShadowedTopLevel => "W0005",
+            Self::UnreachableCode => "W0006",
+            Self::DiscardedValue => "W0007",
+            Self::PossiblyUninitialized => "W0008",
+            Self::UnusedModFile => "W0009",
+        }
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+        .find(|x| x.as_str() == s)
+        .ok_or(format!("Unknown error code `{}`", s))
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A position in a source file; `offset` counts bytes, `line` and the columns are 1-based.
+/// `column` counts Unicode scalar values and `utf16_column` counts UTF-16 code units as LSP clients expect.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Location {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub utf16_column: usize,
+}
+
+impl Location {
+    pub fn new(input: &str, offset: usize) -> Self {
+        let bytes = input.as_bytes();
+        let line =
+            bytes[..offset].iter().enumerate()
+            .filter(|(i, b)| **b == b'\n' || **b == b'\r' && bytes.get(i + 1) != Some(&b'\n'))
+            .count() + 1;
+        let line_prefix = &input[line_head(input, offset)..offset];
+        Self {
+            offset,
+            line,
+            column: line_prefix.chars().count() + 1,
+            utf16_column: line_prefix.encode_utf16().count() + 1,
+        }
+    }
+}
+
+/// A half-open byte range with the corresponding line/column positions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Range {
+    pub start: Location,
+    pub end: Location,
+}
+
+impl Range {
+    pub fn new(input: &str, start: usize, end: usize) -> Self {
+        Self {
+            start: Location::new(input, start),
+            end: Location::new(input, end),
+        }
+    }
+
+    pub fn from_slice(input: &str, slice: &str) -> Option<Self> {
+        let head = input.as_ptr() as usize;
+        let ptr = slice.as_ptr() as usize;
+        if ptr < head || head + input.len() < ptr + slice.len() {
+            return None;
+        }
+        let trimmed = slice.trim();
+        let start = trimmed.as_ptr() as usize - head;
+        Some(Self::new(input, start, start + trimmed.len()))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Label {
+    pub range: Range,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub file: Option<String>,
+    pub severity: Severity,
+    pub code: ErrorCode,
+    pub message: String,
+    pub range: Option<Range>,
+    pub labels: Vec<Label>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: ErrorCode, message: String, range: Option<Range>) -> Self {
+        Self {
+            file: None,
+            severity,
+            code,
+            message,
+            range,
+            labels: Vec::new(),
+            help: None,
+        }
+    }
+
+    pub fn error(code: ErrorCode, message: String) -> Self {
+        Self::new(Severity::Error, code, message, None)
+    }
+
+    pub fn with_help(mut self, help: String) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+
+    /// Translates the message, the labels and the help into `locale`.
+    pub fn localized(mut self, locale: Locale) -> Self {
+        self.message = translate(locale, self.code, &self.message);
+        for label in &mut self.labels {
+            label.message = translate(locale, self.code, &label.message);
+        }
+        self.help = self.help.map(|x| translate(locale, self.code, &x));
+        self
+    }
+
+    /// Formats the diagnostic as `file(line, column): message` followed by the source line, a caret and the help.
+    pub fn render(&self, input: &str) -> String {
+        let help = self.help.as_ref().map_or(String::new(), |x| format!("{}help: {}", NEWLINE, x));
+        format!("{}{}", self.render_message(input), help)
+    }
+
+    fn render_message(&self, input: &str) -> String {
+        let file = self.file.as_deref().unwrap_or("");
+        let Some(range) = self.range
+            else {
+                return format!("{}{}", file, self.message);
+            };
+        let line_slice = line_slice(input, range.start.offset);
+        format!(
+            "{}({}, {}): {}{}{}{}{}",
+            file,
+            range.start.line,
+            range.start.column,
+            self.message,
+            NEWLINE,
+            line_slice,
+            NEWLINE,
+            char_caret(range.start.column, line_slice, &input[range.start.offset..range.end.offset])
+        )
+    }
+}
@@ -0,0 +1,398 @@
+use super::{
+    NEWLINE,
+    diagnostic::ErrorCode,
+};
+
+/// A long-form description of a diagnostic code, with an example reporting it and the fixed one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Explanation {
+    pub code: ErrorCode,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub failing: Option<&'static str>,
+    pub fixed: Option<&'static str>,
+}
+
+impl Explanation {
+    /// Formats the explanation as Markdown.
+    pub fn render(&self) -> String {
+        let mut lines = vec![
+            format!("# {}: {}", self.code, self.title),
+            String::new(),
+            self.description.to_owned(),
+        ];
+        for (heading, example) in [("Erroneous code example:", self.failing), ("Fixed code example:", self.fixed)] {
+            let Some(example) = example
+                else {
+                    continue;
+                };
+            lines.extend([
+                String::new(),
+                heading.to_owned(),
+                String::new(),
+                "```teuchi".to_owned(),
+                example.trim_end().to_owned(),
+                "```".to_owned(),
+            ]);
+        }
+        lines.join(NEWLINE)
+    }
+}
+
+impl ErrorCode {
+    pub fn explain(&self) -> Explanation {
+        let (title, description, failing, fixed) = match self {
+            Self::Internal => (
+                "Internal compiler error",
+                "The compiler reached a state it cannot handle, such as a malformed Udon symbol file. \
+                This is not caused by the source code; please report it with the symbol file in use.",
+                None,
+                None,
+            ),
+            Self::Syntax => (
+                "Syntax error",
+                "The source does not follow the grammar. \
+                The message lists what the parser expected at the point it stopped, and each statement must end with `;`.",
+                Some(r#"let x = 1
+UnityEngine::Debug::Log(x);
+"#),
+                Some(r#"let x = 1;
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::NotFound => (
+                "Name not found",
+                "A variable, type, qualifier, method or file is referred to but is not defined in the current scope. \
+                Check the spelling and whether the item is brought into scope by `use`.",
+                Some(r#"let message = "hello";
+UnityEngine::Debug::Log(mesage);
+"#),
+                Some(r#"let message = "hello";
+UnityEngine::Debug::Log(message);
+"#),
+            ),
+            Self::Duplicated => (
+                "Duplicated definition",
+                "The same name is defined twice where names must be unique, such as enum members or type parameters.",
+                Some(r#"enum State { Idle, Idle };
+"#),
+                Some(r#"enum State { Idle, Running };
+"#),
+            ),
+            Self::Ambiguous => (
+                "Ambiguous name",
+                "A name is found in more than one glob import, or an imported name collides with an existing item. \
+                Qualify the name to pick one.",
+                Some(r#"mod a {
+    pub let greeting = "hello";
+};
+mod b {
+    pub let greeting = "hi";
+};
+use a::*;
+use b::*;
+UnityEngine::Debug::Log(greeting);
+"#),
+                Some(r#"mod a {
+    pub let greeting = "hello";
+};
+mod b {
+    pub let greeting = "hi";
+};
+use a::*;
+use b::*;
+UnityEngine::Debug::Log(a::greeting);
+"#),
+            ),
+            Self::TypeMismatch => (
+                "Mismatched types",
+                "A value has a type other than the one required here, and no implicit conversion exists. \
+                Every branch of `if` and every arm of `match` must have a common type.",
+                Some(r#"let flag = true;
+let x = if flag { "yes" } else { 0 };
+UnityEngine::Debug::Log(x);
+"#),
+                Some(r#"let flag = true;
+let x = if flag { "yes" } else { "no" };
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::InferenceFailed => (
+                "Type inference failed",
+                "The types of the declared variables cannot be determined from the assigned value, \
+                for example when a tuple pattern is bound to a value that is not a tuple of the same length.",
+                Some(r#"let (x, y) = 1;
+"#),
+                Some(r#"let (x, y) = (1, 2);
+UnityEngine::Debug::Log(x);
+UnityEngine::Debug::Log(y);
+"#),
+            ),
+            Self::NotAType => (
+                "Expected a type",
+                "An expression is used where a type is required, such as the right side of `as` or a type argument.",
+                Some(r#"let x = 1 as UnityEngine;
+"#),
+                Some(r#"let x = 1 as int;
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::InvalidTypeArgument => (
+                "Invalid type argument",
+                "Type arguments are passed to something that does not take them, the number of them is wrong, \
+                or a type argument is not compatible with what the method returns.",
+                Some(r#"fn f() { UnityEngine::Debug::Log("f"); };
+f::<int>();
+"#),
+                Some(r#"fn f() { UnityEngine::Debug::Log("f"); };
+f();
+"#),
+            ),
+            Self::InvalidNullable => (
+                "Invalid nullable type",
+                "Only reference types can be made nullable with `?`, and a nullable type cannot be made nullable again.",
+                Some(r#"let x: int? = 1;
+"#),
+                Some(r#"let x: string? = "a";
+if x != null { UnityEngine::Debug::Log(x); };
+"#),
+            ),
+            Self::InvalidOperand => (
+                "Invalid operand",
+                "An operator is applied to operands it does not accept. \
+                For example, the left side of `::` must be a qualifier or a type, and instance members are accessed with `.`.",
+                Some(r#"let x = 1;
+x::ToString();
+"#),
+                Some(r#"let x = 1;
+UnityEngine::Debug::Log(x.ToString());
+"#),
+            ),
+            Self::NoCompatibleMethod => (
+                "No compatible method",
+                "No overload of the method accepts the given arguments, or more than one accepts them equally well.",
+                Some(r#"UnityEngine::Debug::Log(1, 2, 3, 4);
+"#),
+                Some(r#"UnityEngine::Debug::Log(1);
+"#),
+            ),
+            Self::InvalidArgument => (
+                "Invalid argument",
+                "An argument does not match the kind of its parameter. \
+                Arguments for in/out parameters must be mutable variables marked `mut`, and the others must not be marked `mut`.",
+                Some(r#"let mut count = 5;
+System::Threading::Interlocked::Increment(count);
+"#),
+                Some(r#"let mut count = 5;
+let next = System::Threading::Interlocked::Increment(mut count);
+UnityEngine::Debug::Log(next);
+"#),
+            ),
+            Self::PossiblyNull => (
+                "Possibly null value",
+                "With null safety enabled, a value of a nullable type cannot be used as a receiver or an argument \
+                until it is checked against `null`.",
+                Some(r#"let s: string? = null;
+UnityEngine::Debug::Log(s.ToUpper());
+"#),
+                Some(r#"let s: string? = null;
+if s != null { UnityEngine::Debug::Log(s.ToUpper()); };
+"#),
+            ),
+            Self::InvalidPattern => (
+                "Invalid pattern",
+                "A `match` pattern cannot match the value, for example because its type differs, \
+                it is not a constant, or it is a range that matches no value.",
+                Some(r#"let n = 1;
+let x = match n {
+    9..1 => 1,
+    _ => 0,
+};
+UnityEngine::Debug::Log(x);
+"#),
+                Some(r#"let n = 1;
+let x = match n {
+    1..9 => 1,
+    _ => 0,
+};
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::InvalidPublicVar => (
+                "Invalid public variable",
+                "A public variable is shown in the inspector, so it must be a single variable initialized with a literal. \
+                Compute other values at runtime, such as in `Start`.",
+                Some(r#"pub let name = "player".ToUpper();
+"#),
+                Some(r#"pub let name = "PLAYER";
+UnityEngine::Debug::Log(name);
+"#),
+            ),
+            Self::NotStorable => (
+                "Value cannot be stored",
+                "The result of an expression has a type that cannot be held in a variable, such as a type itself.",
+                Some(r#"let t = if true { string } else { string };
+"#),
+                Some(r#"let t = if true { "a" } else { "b" };
+UnityEngine::Debug::Log(t);
+"#),
+            ),
+            Self::OutOfRange => (
+                "Literal out of range",
+                "A numeric literal does not fit in its type. The message shows the allowed range.",
+                Some(r#"let x: byte = 256;
+"#),
+                Some(r#"let x: byte = 255;
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::InvalidEvent => (
+                "Invalid event",
+                "A public function is called by Udon as the event of the same name, so its name must be one of the Udon events. \
+                A public function with any other name never runs unless it is declared as a custom event with `pub custom fn`, \
+                which other behaviours can call through `SendCustomEvent`.",
+                Some(r#"pub fn start() {
+    UnityEngine::Debug::Log("started");
+};
+"#),
+                Some(r#"pub fn Start() {
+    UnityEngine::Debug::Log("started");
+};
+"#),
+            ),
+            Self::InvalidImport => (
+                "Invalid import",
+                "A `use` declaration or a module file cannot be imported. \
+                Imported items must be qualified with the module they belong to.",
+                Some(r#"let base = 4;
+use base;
+"#),
+                Some(r#"mod m {
+    pub let base = 4;
+};
+use m::base;
+UnityEngine::Debug::Log(base);
+"#),
+            ),
+            Self::NonExhaustiveMatch => (
+                "Non-exhaustive match",
+                "Some values are matched by no arm of `match`. Add the missing arms or a `_` arm.",
+                Some(r#"let n = 1;
+let x = match n {
+    1 => 10,
+    2 => 20,
+};
+UnityEngine::Debug::Log(x);
+"#),
+                Some(r#"let n = 1;
+let x = match n {
+    1 => 10,
+    _ => 20,
+};
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::UnusedVariable => (
+                "Unused variable",
+                "A variable is declared but never read. Remove it or use it.",
+                Some(r#"let x = 1;
+"#),
+                Some(r#"let x = 1;
+UnityEngine::Debug::Log(x);
+"#),
+            ),
+            Self::UnusedFunction => (
+                "Unused function",
+                "A function that is not public is never called. Public functions are exempt since Udon may call them as events.",
+                Some(r#"fn greet() { UnityEngine::Debug::Log("hello"); };
+"#),
+                Some(r#"fn greet() { UnityEngine::Debug::Log("hello"); };
+greet();
+"#),
+            ),
+            Self::UnusedPublicVar => (
+                "Unused public variable",
+                "A public variable is never read by the program, so the value set in the inspector has no effect.",
+                Some(r#"pub let speed = 1.5;
+"#),
+                Some(r#"pub let speed = 1.5;
+UnityEngine::Debug::Log(speed);
+"#),
+            ),
+            Self::ShadowedTopLevel => (
+                "Shadowed top-level binding",
+                "A local variable has the same name as a top-level binding, which hides it for the rest of the block.",
+                Some(r#"let count = 1;
+fn f() {
+    let count = 2;
+    UnityEngine::Debug::Log(count);
+};
+f();
+UnityEngine::Debug::Log(count);
+"#),
+                Some(r#"let count = 1;
+fn f() {
+    let local = 2;
+    UnityEngine::Debug::Log(local);
+};
+f();
+UnityEngine::Debug::Log(count);
+"#),
+            ),
+            Self::UnreachableCode => (
+                "Unreachable code",
+                "A statement follows `return`, `break` or `continue` in the same block and is never executed.",
+                Some(r#"fn f() {
+    return;
+    UnityEngine::Debug::Log("never");
+};
+f();
+"#),
+                Some(r#"fn f() {
+    UnityEngine::Debug::Log("once");
+    return;
+};
+f();
+"#),
+            ),
+            Self::DiscardedValue => (
+                "Discarded value",
+                "An expression statement produces a value that is thrown away. Bind it to a variable or pass it on.",
+                Some(r#""hello".ToUpper();
+"#),
+                Some(r#"UnityEngine::Debug::Log("hello".ToUpper());
+"#),
+            ),
+            Self::PossiblyUninitialized => (
+                "Possibly uninitialized variable",
+                "Top-level `let` statements run in the `_start` event, and until then their variables hold `null` or zero. \
+                Events such as `OnEnable` may fire before `_start`, so they should not read such variables. \
+                Use `pub let` with a literal, whose value is set before any event, or initialize the value in the event itself.",
+                Some(r#"let greeting = "hello";
+pub fn OnEnable() {
+    UnityEngine::Debug::Log(greeting);
+};
+"#),
+                Some(r#"pub let greeting = "hello";
+pub fn OnEnable() {
+    UnityEngine::Debug::Log(greeting);
+};
+"#),
+            ),
+            Self::UnusedModFile => (
+                "Unused module file",
+                "A source file passed to the compiler is not declared by any `mod` statement, so it is not compiled. \
+                Declare it with `mod name;` in the entry file, or in `parent.teuchi` for `parent/name.teuchi`.",
+                None,
+                None,
+            ),
+        };
+        Explanation {
+            code: *self,
+            title,
+            description,
+            failing,
+            fixed,
+        }
+    }
+}
@@ -1,10 +1,16 @@
+pub mod catalog;
 pub(crate) mod context_iter;
+pub mod diagnostic;
+pub mod explanation;
 pub(crate) mod parsed_error;
 pub(crate) mod semantic_error;
 
 use std::{
     collections::HashMap,
-    cmp::min,
+    cmp::{
+        max,
+        min,
+    },
     error::Error,
     iter::repeat,
 };
@@ -13,11 +19,12 @@ use nom_supreme::error::{
     GenericErrorTree,
 };
 use context_iter::HasContextIter;
+use diagnostic::Location;
 
 #[cfg(windows)]
-const NEWLINE: &'static str = "\r\n";
+pub(crate) const NEWLINE: &'static str = "\r\n";
 #[cfg(not(windows))]
-const NEWLINE: &'static str = "\n";
+pub(crate) const NEWLINE: &'static str = "\n";
 
 pub type ErrorTree<'input> = GenericErrorTree<&'input str, &'static str, String, Box<dyn Error + Send + Sync + 'static>>;
 
@@ -70,29 +77,11 @@ where
         .sorted_by(|x, y| x.as_ptr().cmp(&y.as_ptr()));
     let filtered = filter_slices(sorted);
     let input_ptr = input.as_ptr() as usize;
-    let input_bytes = input.as_bytes();
-    let mut current_line = if input.len() > 0 && input_bytes[0] == b'\n' { 2 } else { 1 };
-    let mut current_char = if input.len() > 0 && input_bytes[0] == b'\n' { 1 } else { 2 };
     let mut line_char_slice = HashMap::<usize, (usize, usize, &str)>::new();
-    let mut prev_offset = 1usize;
     for s in filtered {
-        let ptr = s.as_ptr() as usize;
-        let trimmed = s.trim().as_ptr() as usize;
-        let offset = trimmed - input_ptr;
-        if offset == 0 {
-            let line_slice = input.lines().next().unwrap_or("");
-            line_char_slice.insert(ptr, (1, 1, line_slice));
-            continue;
-        }
-        let iter_1 = input_bytes[prev_offset - 1..offset - 1].iter();
-        let iter = input_bytes[prev_offset..offset].iter();
-        let line = iter_1.zip(iter).filter(|(x, y)| **x == b'\r' || **y == b'\n').count();
-        let ch = input_bytes[prev_offset..offset].iter().rev().position(|x| *x == b'\r' || *x == b'\n');
-        current_line += line;
-        current_char = ch.map_or(current_char + offset - prev_offset, |x| x + 1);
-        let line_slice = input[offset + 1 - current_char..].lines().next().unwrap_or(&input[offset + 1 - current_char..]);
-        line_char_slice.insert(ptr, (current_line, current_char, line_slice));
-        prev_offset = offset;
+        let offset = s.trim().as_ptr() as usize - input_ptr;
+        let location = Location::new(input, offset);
+        line_char_slice.insert(s.as_ptr() as usize, (location.line, location.column, line_slice(input, offset)));
     }
     e.context_iter()
     .collect::<Vec<_>>()
@@ -140,6 +129,19 @@ fn filter_slices<'input>(
     v
 }
 
+/// Returns the byte offset where the line containing `offset` starts; `\r\n`, `\r` and `\n` all end a line.
+pub(crate) fn line_head(input: &str, offset: usize) -> usize {
+    input[..offset].rfind(['\r', '\n']).map_or(0, |x| x + 1)
+}
+
+/// Returns the line containing `offset` without its line ending.
+pub(crate) fn line_slice(input: &str, offset: usize) -> &str {
+    let line = &input[line_head(input, offset)..];
+    &line[..line.find(['\r', '\n']).unwrap_or(line.len())]
+}
+
+/// Draws carets under `slice`, which starts at the 1-based column `ch` of `line_slice`; both count Unicode scalar values.
 fn char_caret(ch: usize, line_slice: &str, slice: &str) -> String {
-    format!("{}{}", " ".repeat(ch - 1), "^".repeat(min(line_slice.len() + 1 - ch, slice.len())))
+    let rest = line_slice.chars().count() + 1 - ch;
+    format!("{}{}", " ".repeat(ch - 1), "^".repeat(max(1, min(rest, slice.chars().count()))))
 }
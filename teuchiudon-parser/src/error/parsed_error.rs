@@ -1,25 +1,151 @@
+use itertools::Itertools;
+use nom_supreme::error::{
+    GenericErrorTree,
+    StackContext,
+};
 use super::{
-    NEWLINE,
     ErrorTree,
-    char_caret,
-    line_infoes,
+    diagnostic::{
+        Diagnostic,
+        ErrorCode,
+        Range,
+        Severity,
+    },
 };
+use crate::lexer;
+
+pub fn convert_parsed_error(input: &str, e: ErrorTree) -> Vec<Diagnostic> {
+    let mut chains = Vec::new();
+    context_chains(input, &e, &mut Vec::new(), &mut chains);
+    let Some(furthest) = chains.iter().flatten().map(|x| x.0).max()
+        else {
+            return vec![Diagnostic::error(ErrorCode::Syntax, "Parse error".to_owned())];
+        };
+    let expected = chains.iter()
+        .filter_map(|chain| chain.iter().rev().filter(|x| x.0 == furthest).find_map(|x| describe(x.1)))
+        .unique()
+        .collect::<Vec<_>>();
+    let token = found_token(&input[furthest..]);
+    let found = token.map_or("end of file".to_owned(), |x| format!("`{}`", x));
+    let message = if expected.is_empty() {
+        format!("Parse error, found {}", found)
+    }
+    else {
+        format!("Parse error, expected {}, found {}", expected_list(&expected), found)
+    };
+    let diagnostic = Diagnostic::new(
+        Severity::Error,
+        ErrorCode::Syntax,
+        message,
+        Some(Range::new(input, furthest, furthest + token.map_or(0, |x| x.len()))),
+    );
+    let diagnostic = match suggestion(input, furthest, &expected) {
+        Some(help) => diagnostic.with_help(help),
+        None => diagnostic,
+    };
+    vec![diagnostic]
+}
+
+/// Collects the contexts from the innermost to the outermost along each branch, paired with their offsets.
+fn context_chains<'input: 'error, 'error>(
+    input: &'input str,
+    e: &'error ErrorTree<'input>,
+    outer: &mut Vec<(usize, &'error str)>,
+    chains: &mut Vec<Vec<(usize, &'error str)>>,
+) {
+    match e {
+        GenericErrorTree::Base { location: _, kind: _ } => {
+            chains.push(outer.clone());
+        },
+        GenericErrorTree::Stack { base, contexts } => {
+            let mut chain = contexts.iter()
+                .filter_map(|(slice, context)| match context {
+                    StackContext::Context(x) => offset(input, slice).map(|o| (o, x.as_str())),
+                    StackContext::Kind(_) => None,
+                })
+                .collect::<Vec<_>>();
+            chain.extend(outer.iter().cloned());
+            context_chains(input, base, &mut chain, chains);
+        },
+        GenericErrorTree::Alt(v) => {
+            for e in v {
+                context_chains(input, e, outer, chains);
+            }
+        },
+    }
+}
+
+/// Returns the offset of the first token in `slice`, skipping whitespaces and comments.
+fn offset(input: &str, slice: &str) -> Option<usize> {
+    let head = input.as_ptr() as usize;
+    let ptr = slice.as_ptr() as usize;
+    if ptr < head || head + input.len() < ptr + slice.len() {
+        return None;
+    }
+    let slice = lexer::ignore(slice).map_or(slice, |x| x.0);
+    Some(slice.as_ptr() as usize - head)
+}
+
+/// Maps a grammar rule to the description shown to users; rules without a description defer to their inner rules.
+fn describe(context: &str) -> Option<String> {
+    if let Some(x) = context.strip_prefix("keyword: ").or_else(|| context.strip_prefix("op_code: ")) {
+        return Some(format!("`{}`", x));
+    }
+    let description = match context {
+        "top_stat" | "stat" => "statement",
+        "expr" | "term" => "expression",
+        "ty_expr" | "ty_term" => "type",
+        "stats_block" => "block",
+        "pattern" => "pattern",
+        "match_arm" => "match arm",
+        "arg_expr" => "argument",
+        "ident" => "identifier",
+        "unit_literal" => "`()`",
+        "null_literal" => "`null`",
+        "bool_literal" => "boolean",
+        "this_literal" => "`this`",
+        "integer_literal" | "hex_integer_literal" | "bin_integer_literal" | "real_number_literal" => "number",
+        "character_literal" => "character",
+        "regular_string_literal" | "verbatium_string_literal" | "interpolated_string" => "string",
+        "eof" => "end of file",
+        _ => return None,
+    };
+    Some(description.to_owned())
+}
+
+fn expected_list(expected: &[String]) -> String {
+    match expected {
+        [x] => x.clone(),
+        [x, y] => format!("{} or {}", x, y),
+        _ => format!("one of {}", expected.join(", ")),
+    }
+}
 
-pub fn convert_parsed_error(input: &str, e: ErrorTree) -> Vec<String> {
-    let infoes = line_infoes(input, &e);
-    let mut messages = Vec::new();
-    for (line, ch, line_slice, slice, context) in infoes {
-        let mes = format!(
-            "({}, {}): Parse error, expected {}{}{}{}{}",
-            line,
-            ch,
-            context,
-            NEWLINE,
-            line_slice,
-            NEWLINE,
-            char_caret(ch, line_slice, slice)
-        );
-        messages.push(mes);
-    }
-    messages
+fn found_token(rest: &str) -> Option<&str> {
+    let c = rest.chars().next()?;
+    let len =
+        if c.is_alphanumeric() || c == '_' {
+            rest.find(|x: char| !x.is_alphanumeric() && x != '_').unwrap_or(rest.len())
+        }
+        else {
+            c.len_utf8()
+        };
+    Some(&rest[..len])
+}
+
+/// Suggests a fix for a `;` missing at the end of a statement.
+fn suggestion(input: &str, furthest: usize, expected: &[String]) -> Option<String> {
+    if !expected.iter().any(|x| x == "`;`") {
+        return None;
+    }
+    let before = input[..furthest].trim_end();
+    if before.ends_with('}') {
+        Some("Add `;` after the closing `}` of the block".to_owned())
+    }
+    else if input[before.len()..furthest].contains(['\r', '\n']) {
+        Some("Add `;` at the end of the previous line".to_owned())
+    }
+    else {
+        None
+    }
 }
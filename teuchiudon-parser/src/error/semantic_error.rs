@@ -1,25 +1,54 @@
 use crate::semantics::SemanticError;
 use super::{
-    NEWLINE,
-    char_caret,
+    diagnostic::{
+        Diagnostic,
+        Label,
+        Range,
+        Severity,
+    },
     line_infoes,
 };
 
-pub fn convert_semantic_error(input: &str, es: Vec<SemanticError>) -> Vec<String> {
-    let infoes = line_infoes(input, &es);
-    let mut messages = Vec::new();
-    for (line, ch, line_slice, slice, message) in infoes {
-        let mes = format!(
-            "({}, {}): {}{}{}{}{}",
-            line,
-            ch,
-            message,
-            NEWLINE,
-            line_slice,
-            NEWLINE,
-            char_caret(ch, line_slice, slice)
-        );
-        messages.push(mes);
+pub fn convert_semantic_error(input: &str, es: Vec<SemanticError>, severity: Severity) -> Vec<Diagnostic> {
+    let (ranged, unranged): (Vec<_>, Vec<_>) =
+        es.into_iter().partition(|x| x.slice.and_then(|s| Range::from_slice(input, s)).is_some());
+    let infoes = line_infoes(input, &ranged);
+    let mut diagnostics = Vec::new();
+    for (_, _, _, slice, message) in infoes {
+        let Some(e) = ranged.iter().find(|x| x.slice.is_some_and(|s| s.as_ptr() == slice.as_ptr() && s.len() == slice.len()) && &x.message == message)
+            else { continue; };
+        let mut diagnostic = Diagnostic::new(severity, e.code, e.message.clone(), Range::from_slice(input, slice));
+        diagnostic.labels =
+            e.labels.iter()
+            .filter_map(|(s, m)| Range::from_slice(input, s).map(|range| Label { range, message: m.clone() }))
+            .collect();
+        diagnostic.help = e.help.clone();
+        diagnostics.push(diagnostic);
     }
-    messages
+    for e in unranged {
+        let mut diagnostic = Diagnostic::new(severity, e.code, e.message, None);
+        diagnostic.help = e.help;
+        diagnostics.push(diagnostic);
+    }
+    diagnostics
+}
+
+pub fn convert_semantic_error_files(files: &[(&str, &str)], es: Vec<SemanticError>, severity: Severity) -> Vec<Diagnostic> {
+    let mut groups = files.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    for e in es {
+        let index = e.slice.and_then(|s| {
+            let ptr = s.as_ptr() as usize;
+            files.iter().position(|(_, input)| {
+                let head = input.as_ptr() as usize;
+                head <= ptr && ptr <= head + input.len()
+            })
+        });
+        groups[index.unwrap_or(0)].push(e);
+    }
+    files.iter()
+    .zip(groups)
+    .flat_map(|((name, input), es)|
+        convert_semantic_error(input, es, severity).into_iter().map(move |x| x.in_file(name))
+    )
+    .collect()
 }
@@ -12,6 +12,7 @@ pub enum KeywordKind {
     As,
     Break,
     Continue,
+    Custom,
     Else,
     Enum,
     False,
@@ -73,8 +74,10 @@ pub enum OpCodeKind {
     Lt,
     Gt,
     Wildcard,
+    Question,
     Iter,
     Arrow,
+    FatArrow,
     DoubleColon,
     Coalescing,
     CoalescingAccess,
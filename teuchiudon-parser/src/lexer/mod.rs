@@ -74,7 +74,7 @@ where
 }
 
 #[inline]
-fn ignore(input: &str) -> ParsedResult<()> {
+pub(crate) fn ignore(input: &str) -> ParsedResult<()> {
     value((), many0(alt((line_comment, delimited_comment, whitespace1))))(input)
 }
 
@@ -5,10 +5,28 @@ pub mod semantics;
 mod error;
 mod macroes;
 
+pub use error::catalog::{
+    Locale,
+    translate,
+};
+pub use error::diagnostic::{
+    Diagnostic,
+    ErrorCode,
+    Label,
+    Location,
+    Range,
+    Severity,
+};
+pub use error::explanation::Explanation;
+
 #[cfg(test)]
 mod tests;
 
-use std::rc::Rc;
+use std::{
+    collections::BTreeMap,
+    rc::Rc,
+};
+use itertools::Itertools;
 use nom::Err;
 use nom_supreme::final_parser::final_parser;
 use self::{
@@ -16,7 +34,10 @@ use self::{
     error::{
         ErrorTree,
         parsed_error::convert_parsed_error,
-        semantic_error::convert_semantic_error,
+        semantic_error::{
+            convert_semantic_error,
+            convert_semantic_error_files,
+        },
     },
 };
 
@@ -26,16 +47,125 @@ pub type ParsedResult<'input, O> = Result<(&'input str, O), Err<ErrorTree<'input
 pub fn parse<'input: 'context, 'context>(
     context: &'context Context<'input>,
     input: &'input str
-) -> Result<Rc<parser::ast::Target<'input>>, Vec<String>> {
-    final_parser(parser::target(context))(input)
-    .map_err(|e| convert_parsed_error(input, e))
+) -> Result<Rc<parser::ast::Target<'input>>, Vec<Diagnostic>> {
+    match parse_partial(context, input) {
+        (Some(target), errors) if errors.is_empty() => Ok(target),
+        (_, errors) => Err(errors),
+    }
+}
+
+pub fn parse_partial<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    input: &'input str
+) -> (Option<Rc<parser::ast::Target<'input>>>, Vec<Diagnostic>) {
+    context.parse_errors.borrow_mut().clear();
+    context.stashed_parse_error.borrow_mut().take();
+    let result = final_parser(parser::target(context))(input);
+    let mut errors =
+        context.parse_errors.borrow_mut().drain(..)
+        .flat_map(|e| convert_parsed_error(input, e))
+        .collect::<Vec<_>>();
+    let target = match result {
+        Ok(x) => Some(x),
+        Err(e) => {
+            errors.extend(convert_parsed_error(input, parser::with_stashed_error(context, e)));
+            None
+        },
+    };
+    let errors =
+        errors.into_iter()
+        .unique_by(|x| (x.range.map(|r| r.start.offset), x.message.clone()))
+        .sorted_by_key(|x| x.range.map(|r| r.start.offset))
+        .collect();
+    (target, errors)
 }
 
 pub fn analize<'input: 'context, 'context>(
     context: &'context Context<'input>,
     input: &'input str,
     parsed: Rc<parser::ast::Target<'input>>
-) -> Result<Rc<semantics::ast::Target<'input>>, Vec<String>> {
+) -> Result<Rc<semantics::ast::Target<'input>>, Vec<Diagnostic>> {
+    semantics::analyzer::target(context, parsed)
+    .map_err(|e| convert_semantic_error(input, e, Severity::Error))
+}
+
+pub fn warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    input: &'input str
+) -> Vec<Diagnostic> {
+    let ws =
+        context.warning_store.values()
+        .filter(|x| !context.options.suppressed_warnings.contains(&x.code))
+        .map(|x| x.as_ref().clone())
+        .collect();
+    convert_semantic_error(input, ws, Severity::Warning)
+}
+
+pub fn parse_files<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    files: &'input BTreeMap<String, String>,
+    entry: &str
+) -> Result<Rc<parser::ast::Target<'input>>, Vec<Diagnostic>> {
+    match parse_files_partial(context, files, entry) {
+        (Some(target), errors) if errors.is_empty() => Ok(target),
+        (_, errors) => Err(errors),
+    }
+}
+
+pub fn parse_files_partial<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    files: &'input BTreeMap<String, String>,
+    entry: &str
+) -> (Option<Rc<parser::ast::Target<'input>>>, Vec<Diagnostic>) {
+    let mut target = None;
+    let mut errors = Vec::new();
+    for (name, input) in files {
+        let (parsed, es) = parse_partial(context, input);
+        errors.extend(es.into_iter().map(|x| x.in_file(name)));
+        match parsed {
+            Some(x) if name == entry =>
+                target = Some(x),
+            Some(x) => {
+                context.mod_files.borrow_mut().insert(name.clone(), x);
+            },
+            None => (),
+        }
+    }
+    if target.is_none() && !files.contains_key(entry) {
+        errors.push(Diagnostic::error(ErrorCode::InvalidImport, format!("Entry file `{}` not found", entry)));
+    }
+    (target, errors)
+}
+
+pub fn analize_files<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    files: &'input BTreeMap<String, String>,
+    entry: &str,
+    parsed: Rc<parser::ast::Target<'input>>
+) -> Result<Rc<semantics::ast::Target<'input>>, Vec<Diagnostic>> {
     semantics::analyzer::target(context, parsed)
-    .map_err(|e| convert_semantic_error(input, e))
+    .map_err(|e| convert_semantic_error_files(&file_slices(files, entry), e, Severity::Error))
+}
+
+pub fn warnings_files<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    files: &'input BTreeMap<String, String>,
+    entry: &str
+) -> Vec<Diagnostic> {
+    let ws =
+        context.warning_store.values()
+        .filter(|x| !context.options.suppressed_warnings.contains(&x.code))
+        .map(|x| x.as_ref().clone())
+        .collect();
+    convert_semantic_error_files(&file_slices(files, entry), ws, Severity::Warning)
+}
+
+fn file_slices<'input>(
+    files: &'input BTreeMap<String, String>,
+    entry: &str
+) -> Vec<(&'input str, &'input str)> {
+    files.iter()
+    .sorted_by_key(|(name, _)| name.as_str() != entry)
+    .map(|(name, input)| (name.as_str(), input.as_str()))
+    .collect()
 }
@@ -28,11 +28,25 @@ pub enum TopStatKind<'input> {
     },
     FnBind {
         access_attr: Option<Rc<AccessAttr<'input>>>,
+        ev_attr: Option<Rc<EvAttr<'input>>>,
         fn_bind: Rc<FnBind<'input>>,
     },
+    EnumBind {
+        enum_bind: Rc<EnumBind<'input>>,
+    },
+    NewtyBind {
+        newty_bind: Rc<NewtyBind<'input>>,
+    },
+    ModBind {
+        mod_bind: Rc<ModBind<'input>>,
+    },
+    UseBind {
+        use_bind: Rc<UseBind<'input>>,
+    },
     Stat {
         stat: Rc<Stat<'input>>,
     },
+    Error,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -47,6 +61,12 @@ pub struct SyncAttr<'input> {
     pub attr: Rc<lexer::ast::Keyword<'input>>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvAttr<'input> {
+    pub slice: &'input str,
+    pub attr: Rc<lexer::ast::Keyword<'input>>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct VarBind<'input> {
     pub slice: &'input str,
@@ -95,6 +115,60 @@ pub struct FnDecl<'input> {
     pub ty_expr: Option<Rc<TyExpr<'input>>>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumBind<'input> {
+    pub slice: &'input str,
+    pub enum_keyword: Rc<lexer::ast::Keyword<'input>>,
+    pub ident: Rc<lexer::ast::Ident<'input>>,
+    pub members: Vec<Rc<lexer::ast::Ident<'input>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtyBind<'input> {
+    pub slice: &'input str,
+    pub newty_keyword: Rc<lexer::ast::Keyword<'input>>,
+    pub ident: Rc<lexer::ast::Ident<'input>>,
+    pub ty_expr: Rc<TyExpr<'input>>,
+    pub methods: Vec<Rc<lexer::ast::Ident<'input>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModBind<'input> {
+    pub slice: &'input str,
+    pub mod_keyword: Rc<lexer::ast::Keyword<'input>>,
+    pub ident: Rc<lexer::ast::Ident<'input>>,
+    pub top_stats: Option<Vec<Rc<TopStat<'input>>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBind<'input> {
+    pub slice: &'input str,
+    pub use_keyword: Rc<lexer::ast::Keyword<'input>>,
+    pub idents: Vec<Rc<lexer::ast::Ident<'input>>>,
+    pub kind: Rc<UseBindKind<'input>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UseBindKind<'input> {
+    Single,
+    Alias {
+        as_keyword: Rc<lexer::ast::Keyword<'input>>,
+        ident: Rc<lexer::ast::Ident<'input>>,
+    },
+    Glob {
+        op_code: Rc<lexer::ast::OpCode<'input>>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TyBind<'input> {
+    pub slice: &'input str,
+    pub ty_keyword: Rc<lexer::ast::Keyword<'input>>,
+    pub ident: Rc<lexer::ast::Ident<'input>>,
+    pub ty_params: Vec<Rc<lexer::ast::Ident<'input>>>,
+    pub ty_expr: Rc<TyExpr<'input>>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TyExpr<'input> {
     pub slice: &'input str,
@@ -114,6 +188,12 @@ pub enum TyOpKind<'input> {
         op_code: Rc<lexer::ast::OpCode<'input>>,
         ty_term: Rc<TyTerm<'input>>,
     },
+    Apply {
+        ty_exprs: Vec<Rc<TyExpr<'input>>>,
+    },
+    Nullable {
+        op_code: Rc<lexer::ast::OpCode<'input>>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -160,9 +240,13 @@ pub enum StatKind<'input> {
     FnBind {
         fn_bind: Rc<FnBind<'input>>,
     },
+    TyBind {
+        ty_bind: Rc<TyBind<'input>>,
+    },
     Expr {
         expr: Rc<Expr<'input>>,
     },
+    Error,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -189,6 +273,7 @@ pub enum OpKind<'input> {
         term: Rc<Term<'input>>,
     },
     EvalFn {
+        ty_exprs: Vec<Rc<TyExpr<'input>>>,
         arg_exprs: Vec<Rc<ArgExpr<'input>>>,
     },
     EvalSpreadFn {
@@ -257,6 +342,11 @@ pub enum TermKind<'input> {
         if_part: Rc<StatsBlock<'input>>,
         else_part: Option<(Rc<lexer::ast::Keyword<'input>>, Rc<StatsBlock<'input>>)>,
     },
+    Match {
+        match_keyword: Rc<lexer::ast::Keyword<'input>>,
+        expr: Rc<Expr<'input>>,
+        match_arms: Vec<Rc<MatchArm<'input>>>,
+    },
     While {
         while_keyword: Rc<lexer::ast::Keyword<'input>>,
         condition: Rc<Expr<'input>>,
@@ -276,6 +366,39 @@ pub enum TermKind<'input> {
     },
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchArm<'input> {
+    pub slice: &'input str,
+    pub pattern: Rc<Pattern<'input>>,
+    pub expr: Rc<Expr<'input>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern<'input> {
+    pub slice: &'input str,
+    pub kind: Rc<PatternKind<'input>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternKind<'input> {
+    Wildcard {
+        op_code: Rc<lexer::ast::OpCode<'input>>,
+    },
+    Literal {
+        literal: Rc<lexer::ast::Literal<'input>>,
+    },
+    Range {
+        left: Rc<lexer::ast::Literal<'input>>,
+        right: Rc<lexer::ast::Literal<'input>>,
+    },
+    Tuple {
+        patterns: Vec<Rc<Pattern<'input>>>,
+    },
+    EvalVar {
+        idents: Vec<Rc<lexer::ast::Ident<'input>>>,
+    },
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct IterExpr<'input> {
     pub slice: &'input str,
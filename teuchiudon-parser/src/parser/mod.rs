@@ -3,13 +3,21 @@ pub mod ast;
 use std::rc::Rc;
 use function_name::named;
 use nom::{
+    Err,
     Parser,
     branch::alt,
+    error::ParseError,
+    character::complete::{
+        anychar,
+        char,
+    },
     combinator::{
         consumed,
         map,
         opt,
+        peek,
         value,
+        verify,
     },
     multi::{
         many0,
@@ -24,10 +32,13 @@ use nom::{
         tuple,
     }
 };
-use nom_supreme::ParserExt;
+use nom_supreme::{
+    ParserExt,
+    error::GenericErrorTree,
+};
 
 use super::ParsedResult;
-use crate::{context::Context, parser::ast::TyTermKind};
+use crate::{context::Context, error::ErrorTree, parser::ast::TyTermKind};
 use crate::lexer::{
     self,
     lex,
@@ -56,7 +67,7 @@ pub fn body<'input: 'context, 'context>(
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Body<'input>>> + 'context {
     |input: &'input str| map(
         consumed(
-            many1(top_stat(context)),
+            verify(top_stats(context, true), |x: &Vec<_>| !x.is_empty()),
         ),
         |x| Rc::new(ast::Body { slice: x.0, top_stats: x.1 }),
     )
@@ -64,6 +75,131 @@ pub fn body<'input: 'context, 'context>(
     .parse(input)
 }
 
+fn top_stats<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    skip_close: bool,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Vec<Rc<ast::TopStat<'input>>>> + 'context {
+    move |input: &'input str| {
+        let mut input = input;
+        let mut top_stats = Vec::new();
+        loop {
+            match top_stat(context)(input) {
+                Ok((rest, x)) => {
+                    top_stats.push(x);
+                    input = rest;
+                },
+                Err(Err::Error(e)) => {
+                    let Some(rest) = skip_to_sync(input, skip_close)
+                        else {
+                            return Ok((input, top_stats));
+                        };
+                    context.parse_errors.borrow_mut().push(with_stashed_error(context, e));
+                    top_stats.push(Rc::new(ast::TopStat {
+                        slice: &input[..input.len() - rest.len()],
+                        kind: Rc::new(ast::TopStatKind::Error),
+                    }));
+                    input = rest;
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Merges the error stashed by `many0_stashing` into `e` if it got further into the input.
+pub(crate) fn with_stashed_error<'input>(context: &Context<'input>, e: ErrorTree<'input>) -> ErrorTree<'input> {
+    match context.stashed_parse_error.borrow_mut().take() {
+        Some(x) if remaining_len(&x) < remaining_len(&e) => e.or(x),
+        _ => e,
+    }
+}
+
+/// Like `many0`, but stashes the error that ended the repetition, which would otherwise be dropped
+/// even when the last item failed past its first token, as in `1 +;`.
+fn many0_stashing<'input: 'context, 'context, O>(
+    context: &'context Context<'input>,
+    mut parser: impl FnMut(&'input str) -> ParsedResult<'input, O> + 'context,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Vec<O>> + 'context {
+    move |input: &'input str| {
+        let mut input = input;
+        let mut outputs = Vec::new();
+        loop {
+            match parser(input) {
+                Ok((rest, x)) if rest.len() < input.len() => {
+                    outputs.push(x);
+                    input = rest;
+                },
+                Ok(_) =>
+                    return Ok((input, outputs)),
+                Err(Err::Error(e)) => {
+                    let mut stashed = context.stashed_parse_error.borrow_mut();
+                    if stashed.as_ref().is_none_or(|x| remaining_len(&e) < remaining_len(x)) {
+                        *stashed = Some(e);
+                    }
+                    return Ok((input, outputs));
+                },
+                Err(e) =>
+                    return Err(e),
+            }
+        }
+    }
+}
+
+/// Returns the length of the input left at the furthest token `e` reached.
+fn remaining_len(e: &ErrorTree) -> usize {
+    match e {
+        GenericErrorTree::Base { location, kind: _ } =>
+            lexer::ignore(location).map_or(*location, |x| x.0).len(),
+        GenericErrorTree::Stack { base, contexts: _ } =>
+            remaining_len(base),
+        GenericErrorTree::Alt(v) =>
+            v.iter().map(remaining_len).min().unwrap_or(usize::MAX),
+    }
+}
+
+/// Skips a broken statement up to and including its `;` (or a balanced `{ ... }`), stopping before an unmatched `}`.
+fn skip_to_sync(input: &str, skip_close: bool) -> Option<&str> {
+    let mut rest = input;
+    let mut depth = 0usize;
+    while let Ok((next, token)) = sync_token(rest) {
+        match token {
+            Some('}') if depth == 0 => {
+                if skip_close && rest.len() == input.len() {
+                    rest = next;
+                }
+                break;
+            },
+            Some(';') if depth == 0 => {
+                rest = next;
+                break;
+            },
+            Some('{') => depth += 1,
+            Some('}') => {
+                depth -= 1;
+                if depth == 0 {
+                    rest = preceded(lexer::ignore, char(';'))(next).map_or(next, |x| x.0);
+                    break;
+                }
+            },
+            _ => (),
+        }
+        rest = next;
+    }
+    if rest.len() == input.len() { None } else { Some(rest) }
+}
+
+fn sync_token<'input>(input: &'input str) -> ParsedResult<'input, Option<char>> {
+    preceded(
+        lexer::ignore,
+        alt((
+            value(None, lexer::unwrap_fn(lexer::character_literal)),
+            value(None, lexer::unwrap_fn(lexer::regular_string_literal)),
+            value(None, lexer::unwrap_fn(lexer::verbatium_string_literal)),
+            map(anychar, Some),
+        )),
+    )(input)
+}
+
 #[named]
 pub fn top_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
@@ -71,6 +207,10 @@ pub fn top_stat<'input: 'context, 'context>(
     |input: &'input str| alt((
         var_bind_top_stat(context),
         fn_bind_top_stat(context),
+        enum_bind_top_stat(context),
+        newty_bind_top_stat(context),
+        mod_bind_top_stat(context),
+        use_bind_top_stat(context),
         stat_top_stat(context),
     ))
     .context(function_name!().to_owned())
@@ -117,6 +257,9 @@ fn fn_bind_top_stat<'input: 'context, 'context>(
                 consumed(
                     opt(lex(lexer::keyword(context, "pub"))),
                 ),
+                consumed(
+                    opt(lex(lexer::keyword(context, "custom"))),
+                ),
                 fn_bind(context),
                 lex(lexer::op_code(context, ";")),
             )),
@@ -125,12 +268,81 @@ fn fn_bind_top_stat<'input: 'context, 'context>(
             slice: x.0,
             kind: Rc::new(ast::TopStatKind::FnBind {
                 access_attr: x.1.0.1.map(|y| Rc::new(ast::AccessAttr { slice: x.1.0.0, attr: y })),
-                fn_bind: x.1.1,
+                ev_attr: x.1.1.1.map(|y| Rc::new(ast::EvAttr { slice: x.1.1.0, attr: y })),
+                fn_bind: x.1.2,
             }),
         }),
     )(input)
 }
 
+fn enum_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TopStat<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            terminated(
+                enum_bind(context),
+                lex(lexer::op_code(context, ";")),
+            ),
+        ),
+        |x| Rc::new(ast::TopStat {
+            slice: x.0,
+            kind: Rc::new(ast::TopStatKind::EnumBind { enum_bind: x.1 }),
+        }),
+    )(input)
+}
+
+fn newty_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TopStat<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            terminated(
+                newty_bind(context),
+                lex(lexer::op_code(context, ";")),
+            ),
+        ),
+        |x| Rc::new(ast::TopStat {
+            slice: x.0,
+            kind: Rc::new(ast::TopStatKind::NewtyBind { newty_bind: x.1 }),
+        }),
+    )(input)
+}
+
+fn mod_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TopStat<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            terminated(
+                mod_bind(context),
+                lex(lexer::op_code(context, ";")),
+            ),
+        ),
+        |x| Rc::new(ast::TopStat {
+            slice: x.0,
+            kind: Rc::new(ast::TopStatKind::ModBind { mod_bind: x.1 }),
+        }),
+    )(input)
+}
+
+fn use_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TopStat<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            terminated(
+                use_bind(context),
+                lex(lexer::op_code(context, ";")),
+            ),
+        ),
+        |x| Rc::new(ast::TopStat {
+            slice: x.0,
+            kind: Rc::new(ast::TopStatKind::UseBind { use_bind: x.1 }),
+        }),
+    )(input)
+}
+
 fn stat_top_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TopStat<'input>>> + 'context {
@@ -290,6 +502,172 @@ pub fn fn_decl<'input: 'context, 'context>(
     .parse(input)
 }
 
+#[named]
+pub fn enum_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::EnumBind<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "enum")),
+                lex(lexer::ident(context)),
+                delimited(
+                    lex(lexer::op_code(context, "{")),
+                    terminated(
+                        separated_list1(lex(lexer::op_code(context, ",")), lex(lexer::ident(context))),
+                        opt(lex(lexer::op_code(context, ","))),
+                    ),
+                    lex(lexer::op_code(context, "}")),
+                ),
+            )),
+        ),
+        |x| Rc::new(ast::EnumBind {
+            slice: x.0,
+            enum_keyword: x.1.0,
+            ident: x.1.1,
+            members: x.1.2,
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+#[named]
+pub fn newty_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::NewtyBind<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "newty")),
+                lex(lexer::ident(context)),
+                lex(lexer::op_code(context, "=")),
+                ty_expr(context),
+                opt(
+                    delimited(
+                        lex(lexer::op_code(context, "{")),
+                        terminated(
+                            separated_list1(lex(lexer::op_code(context, ",")), lex(lexer::ident(context))),
+                            opt(lex(lexer::op_code(context, ","))),
+                        ),
+                        lex(lexer::op_code(context, "}")),
+                    ),
+                ),
+            )),
+        ),
+        |x| Rc::new(ast::NewtyBind {
+            slice: x.0,
+            newty_keyword: x.1.0,
+            ident: x.1.1,
+            ty_expr: x.1.3,
+            methods: x.1.4.unwrap_or_default(),
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+#[named]
+pub fn mod_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::ModBind<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "mod")),
+                lex(lexer::ident(context)),
+                opt(
+                    delimited(
+                        lex(lexer::op_code(context, "{")),
+                        top_stats(context, false),
+                        lex(lexer::op_code(context, "}")),
+                    ),
+                ),
+            )),
+        ),
+        |x| Rc::new(ast::ModBind {
+            slice: x.0,
+            mod_keyword: x.1.0,
+            ident: x.1.1,
+            top_stats: x.1.2,
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+#[named]
+pub fn use_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::UseBind<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "use")),
+                separated_list1(lex(lexer::op_code(context, "::")), lex(lexer::ident(context))),
+                opt(
+                    alt((
+                        map(
+                            preceded(
+                                lex(lexer::op_code(context, "::")),
+                                lex(lexer::op_code(context, "*")),
+                            ),
+                            |x| Rc::new(ast::UseBindKind::Glob { op_code: x }),
+                        ),
+                        map(
+                            tuple((
+                                lex(lexer::keyword(context, "as")),
+                                lex(lexer::ident(context)),
+                            )),
+                            |x| Rc::new(ast::UseBindKind::Alias { as_keyword: x.0, ident: x.1 }),
+                        ),
+                    )),
+                ),
+            )),
+        ),
+        |x| Rc::new(ast::UseBind {
+            slice: x.0,
+            use_keyword: x.1.0,
+            idents: x.1.1,
+            kind: x.1.2.unwrap_or(Rc::new(ast::UseBindKind::Single)),
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+#[named]
+pub fn ty_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TyBind<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "ty")),
+                lex(lexer::ident(context)),
+                opt(
+                    delimited(
+                        lex(lexer::op_code(context, "<")),
+                        separated_list1(lex(lexer::op_code(context, ",")), lex(lexer::ident(context))),
+                        lex(lexer::op_code(context, ">")),
+                    ),
+                ),
+                lex(lexer::op_code(context, "=")),
+                ty_expr(context),
+            )),
+        ),
+        |x| Rc::new(ast::TyBind {
+            slice: x.0,
+            ty_keyword: x.1.0,
+            ident: x.1.1,
+            ty_params: x.1.2.unwrap_or_default(),
+            ty_expr: x.1.4,
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
 #[named]
 pub fn ty_expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
@@ -314,6 +692,8 @@ pub fn ty_op<'input: 'context, 'context>(
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TyOp<'input>>> + 'context {
     |input: &'input str| alt((
         access_ty_op(context),
+        apply_ty_op(context),
+        nullable_ty_op(context),
     ))
     .context(function_name!().to_owned())
     .parse(input)
@@ -339,6 +719,42 @@ fn access_ty_op<'input: 'context, 'context>(
     )(input)
 }
 
+fn apply_ty_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TyOp<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            delimited(
+                lex(lexer::op_code(context, "<")),
+                separated_list1(lex(lexer::op_code(context, ",")), ty_expr(context)),
+                lex(lexer::op_code(context, ">")),
+            ),
+        ),
+        |x| Rc::new(ast::TyOp {
+            slice: x.0,
+            kind: Rc::new(ast::TyOpKind::Apply {
+                ty_exprs: x.1,
+            }),
+        }),
+    )(input)
+}
+
+fn nullable_ty_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::TyOp<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            lex(lexer::op_code(context, "?")),
+        ),
+        |x| Rc::new(ast::TyOp {
+            slice: x.0,
+            kind: Rc::new(ast::TyOpKind::Nullable {
+                op_code: x.1,
+            }),
+        }),
+    )(input)
+}
+
 #[named]
 pub fn ty_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
@@ -372,10 +788,7 @@ pub fn stats_block<'input: 'context, 'context>(
         consumed(
             delimited(
                 lex(lexer::op_code(context, "{")),
-                tuple((
-                    many0(stat(context)),
-                    opt(expr(context)),
-                )),
+                stats_and_ret(context),
                 lex(lexer::op_code(context, "}")),
             ),
         ),
@@ -389,6 +802,41 @@ pub fn stats_block<'input: 'context, 'context>(
     .parse(input)
 }
 
+type StatsAndRet<'input> = (Vec<Rc<ast::Stat<'input>>>, Option<Rc<ast::Expr<'input>>>);
+
+fn stats_and_ret<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, StatsAndRet<'input>> + 'context {
+    |input: &'input str| {
+        let mut input = input;
+        let mut stats = Vec::new();
+        loop {
+            match stat(context)(input) {
+                Ok((rest, x)) => {
+                    stats.push(x);
+                    input = rest;
+                },
+                Err(Err::Error(e)) => {
+                    if let Ok((rest, ret)) = terminated(opt(expr(context)), peek(lex(lexer::op_code(context, "}"))))(input) {
+                        return Ok((rest, (stats, ret)));
+                    }
+                    let Some(rest) = skip_to_sync(input, false)
+                        else {
+                            return Err(Err::Error(e));
+                        };
+                    context.parse_errors.borrow_mut().push(with_stashed_error(context, e));
+                    stats.push(Rc::new(ast::Stat {
+                        slice: &input[..input.len() - rest.len()],
+                        kind: Rc::new(ast::StatKind::Error),
+                    }));
+                    input = rest;
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
 #[named]
 pub fn stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
@@ -412,6 +860,13 @@ pub fn stat<'input: 'context, 'context>(
                     kind: Rc::new(ast::StatKind::FnBind { fn_bind: x.1 }),
                 }),
             ),
+            map(
+                consumed(ty_bind(context)),
+                |x| Rc::new(ast::Stat {
+                    slice: x.0,
+                    kind: Rc::new(ast::StatKind::TyBind { ty_bind: x.1 }),
+                }),
+            ),
             map(
                 consumed(expr(context)),
                 |x| Rc::new(ast::Stat {
@@ -477,7 +932,7 @@ pub fn expr<'input: 'context, 'context>(
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Expr<'input>>> + 'context {
     |input: &'input str| map(
         consumed(
-            tuple((term(context), many0(op(context)))),
+            tuple((term(context), many0_stashing(context, op(context)))),
         ),
         |x| Rc::new(ast::Expr {
             slice: x.0,
@@ -546,20 +1001,35 @@ fn eval_fn_op<'input: 'context, 'context>(
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Op<'input>>> + 'context {
     |input: &'input str| map(
         consumed(
-            delimited(
-                lex(lexer::op_code(context, "(")),
+            tuple((
                 opt(
-                    terminated(
-                        separated_list1(lex(lexer::op_code(context, ",")), arg_expr(context)),
-                        opt(lex(lexer::op_code(context, ","))),
+                    preceded(
+                        lex(lexer::op_code(context, "::")),
+                        delimited(
+                            lex(lexer::op_code(context, "<")),
+                            separated_list1(lex(lexer::op_code(context, ",")), ty_expr(context)),
+                            lex(lexer::op_code(context, ">")),
+                        ),
                     ),
                 ),
-                lex(lexer::op_code(context, ")")),
-            ),
+                delimited(
+                    lex(lexer::op_code(context, "(")),
+                    opt(
+                        terminated(
+                            separated_list1(lex(lexer::op_code(context, ",")), arg_expr(context)),
+                            opt(lex(lexer::op_code(context, ","))),
+                        ),
+                    ),
+                    lex(lexer::op_code(context, ")")),
+                ),
+            )),
         ),
         |x| Rc::new(ast::Op {
             slice: x.0,
-            kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: x.1.unwrap_or(Vec::new()) }),
+            kind: Rc::new(ast::OpKind::EvalFn {
+                ty_exprs: x.1.0.unwrap_or(Vec::new()),
+                arg_exprs: x.1.1.unwrap_or(Vec::new()),
+            }),
         }),
     )(input)
 }
@@ -694,6 +1164,7 @@ pub fn term<'input: 'context, 'context>(
         eval_var_term(context),
         let_in_bind_term(context),
         if_term(context),
+        match_term(context),
         while_term(context),
         loop_term(context),
         for_term(context),
@@ -816,18 +1287,7 @@ fn literal_term<'input: 'context, 'context>(
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Term<'input>>> + 'context {
     |input: &'input str| map(
         consumed(
-            alt((
-                lex(lexer::unit_literal(context)),
-                lex(lexer::null_literal(context)),
-                lex(lexer::bool_literal(context)),
-                lex(lexer::real_number_literal),
-                lex(lexer::hex_integer_literal),
-                lex(lexer::bin_integer_literal),
-                lex(lexer::integer_literal),
-                lex(lexer::character_literal),
-                lex(lexer::regular_string_literal),
-                lex(lexer::verbatium_string_literal),
-            )),
+            literal(context),
         ),
         |x| Rc::new(ast::Term {
             slice: x.0,
@@ -836,6 +1296,23 @@ fn literal_term<'input: 'context, 'context>(
     )(input)
 }
 
+fn literal<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<lexer::ast::Literal<'input>>> + 'context {
+    |input: &'input str| alt((
+        lex(lexer::unit_literal(context)),
+        lex(lexer::null_literal(context)),
+        lex(lexer::bool_literal(context)),
+        lex(lexer::real_number_literal),
+        lex(lexer::hex_integer_literal),
+        lex(lexer::bin_integer_literal),
+        lex(lexer::integer_literal),
+        lex(lexer::character_literal),
+        lex(lexer::regular_string_literal),
+        lex(lexer::verbatium_string_literal),
+    ))(input)
+}
+
 fn this_literal_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Term<'input>>> + 'context {
@@ -936,6 +1413,31 @@ fn if_term<'input: 'context, 'context>(
     )(input)
 }
 
+fn match_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Term<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            tuple((
+                lex(lexer::keyword(context, "match")),
+                expr(context),
+                delimited(
+                    lex(lexer::op_code(context, "{")),
+                    terminated(
+                        separated_list1(lex(lexer::op_code(context, ",")), match_arm(context)),
+                        opt(lex(lexer::op_code(context, ","))),
+                    ),
+                    lex(lexer::op_code(context, "}")),
+                ),
+            )),
+        ),
+        |x| Rc::new(ast::Term {
+            slice: x.0,
+            kind: Rc::new(ast::TermKind::Match { match_keyword: x.1.0, expr: x.1.1, match_arms: x.1.2 }),
+        }),
+    )(input)
+}
+
 fn while_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
 ) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Term<'input>>> + 'context {
@@ -1010,6 +1512,124 @@ fn closure_term<'input: 'context, 'context>(
     )(input)
 }
 
+#[named]
+pub fn match_arm<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::MatchArm<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            separated_pair(
+                pattern(context),
+                lex(lexer::op_code(context, "=>")),
+                expr(context),
+            ),
+        ),
+        |x| Rc::new(ast::MatchArm {
+            slice: x.0,
+            pattern: x.1.0,
+            expr: x.1.1,
+        }),
+    )
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+#[named]
+pub fn pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| alt((
+        wildcard_pattern(context),
+        range_pattern(context),
+        literal_pattern(context),
+        tuple_pattern(context),
+        eval_var_pattern(context),
+    ))
+    .context(function_name!().to_owned())
+    .parse(input)
+}
+
+fn wildcard_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            lex(lexer::op_code(context, "_")),
+        ),
+        |x| Rc::new(ast::Pattern {
+            slice: x.0,
+            kind: Rc::new(ast::PatternKind::Wildcard { op_code: x.1 }),
+        }),
+    )(input)
+}
+
+fn range_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            separated_pair(
+                literal(context),
+                lex(lexer::op_code(context, "..")),
+                literal(context),
+            ),
+        ),
+        |x| Rc::new(ast::Pattern {
+            slice: x.0,
+            kind: Rc::new(ast::PatternKind::Range { left: x.1.0, right: x.1.1 }),
+        }),
+    )(input)
+}
+
+fn literal_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            literal(context),
+        ),
+        |x| Rc::new(ast::Pattern {
+            slice: x.0,
+            kind: Rc::new(ast::PatternKind::Literal { literal: x.1 }),
+        }),
+    )(input)
+}
+
+fn tuple_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            delimited(
+                lex(lexer::op_code(context, "(")),
+                terminated(
+                    separated_list1(lex(lexer::op_code(context, ",")), pattern(context)),
+                    opt(lex(lexer::op_code(context, ","))),
+                ),
+                lex(lexer::op_code(context, ")")),
+            ),
+        ),
+        |x| Rc::new(ast::Pattern {
+            slice: x.0,
+            kind: Rc::new(ast::PatternKind::Tuple { patterns: x.1 }),
+        }),
+    )(input)
+}
+
+fn eval_var_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> impl FnMut(&'input str) -> ParsedResult<'input, Rc<ast::Pattern<'input>>> + 'context {
+    |input: &'input str| map(
+        consumed(
+            separated_list1(lex(lexer::op_code(context, "::")), lex(lexer::ident(context))),
+        ),
+        |x| Rc::new(ast::Pattern {
+            slice: x.0,
+            kind: Rc::new(ast::PatternKind::EvalVar { idents: x.1 }),
+        }),
+    )(input)
+}
+
 #[named]
 pub fn iter_expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
@@ -3,18 +3,23 @@ use std::{
     collections::VecDeque,
     rc::Rc,
 };
+use itertools::Itertools;
 use crate::context::Context;
+use crate::error::diagnostic::ErrorCode;
 use crate::lexer;
 use crate::parser;
 use super::{
     ast,
+    dataflow,
     SemanticError,
     elements::{
         ElementError,
         element::{
             KeyElement,
+            SemanticElement,
             ValueElement,
         },
+        base_ty::BaseTy,
         ev::Ev,
         ev_stats::EvStats,
         eval_fn::EvalFn,
@@ -24,10 +29,29 @@ use super::{
             DataLabelKind,
         },
         literal::Literal,
-        method::MethodParamInOut,
+        method::{
+            Method,
+            MethodKey,
+            MethodParamInOut,
+        },
+        named_methods::{
+            NamedMethods,
+            NamedMethodsKey,
+        },
+        qual::Qual,
         scope::Scope,
         top_stat::TopStat,
-        ty::Ty,
+        ty::{
+            Ty,
+            TyArg,
+            TyInstance,
+            TyKey,
+            TyLogicalKey,
+        },
+        ty_alias::{
+            TyAlias,
+            TyAliasKey,
+        },
         valued_var::ValuedVar,
         var::Var,
     },
@@ -41,6 +65,13 @@ pub fn target<'input: 'context, 'context>(
         Some(x) => body(context, x.clone())?,
         None => empty_body(context)?,
     };
+    let errors = context.semantic_errors.take();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    unused_warnings(context);
+    unused_mod_file_warnings(context);
+    dataflow::uninitialized_warnings(context, &body);
     Ok(Rc::new(ast::Target {
         parsed: Some(node),
         body,
@@ -53,7 +84,8 @@ pub fn body<'input: 'context, 'context>(
 ) -> Result<Rc<ast::Body<'input>>, Vec<SemanticError<'input>>> {
     let top_stats =
         node.top_stats.iter()
-        .map(|x| top_stat(context, x.clone()))
+        .filter(|x| !matches!(x.kind.as_ref(), parser::ast::TopStatKind::Error))
+        .map(|x| recovered_top_stat(context, x.clone()))
         .collect::<Result<_, _>>()?;
     Ok(Rc::new(ast::Body {
         parsed: Some(node),
@@ -61,6 +93,36 @@ pub fn body<'input: 'context, 'context>(
     }))
 }
 
+fn unused_warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) {
+    let used_var_ids = context.used_var_ids.borrow();
+    for (var, slice, code) in context.bindings.borrow().iter() {
+        if used_var_ids.contains(&var.id) {
+            continue;
+        }
+        let message = match code {
+            ErrorCode::UnusedFunction => format!("Function `{}` is never used", var.name),
+            ErrorCode::UnusedPublicVar => format!("Public variable `{}` is never read", var.name),
+            _ => format!("Variable `{}` is never used", var.name),
+        };
+        context.warning_store.push(Rc::new(SemanticError::new(*code, Some(slice), message)));
+    }
+}
+
+fn unused_mod_file_warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) {
+    for (file_name, target) in context.mod_files.borrow().iter().sorted_by_key(|(x, _)| x.as_str()) {
+        let path = file_name.strip_suffix(".teuchi").unwrap_or(file_name);
+        let name = path.rsplit('/').next().unwrap_or(path);
+        context.warning_store.push(Rc::new(
+            SemanticError::new(ErrorCode::UnusedModFile, Some(&target.slice[..0]), format!("Module file `{}` is never declared", file_name))
+            .with_help(format!("Declare it with `mod {};` in its parent module to compile it", name))
+        ));
+    }
+}
+
 fn empty_body<'input: 'context, 'context>(
     _context: &'context Context<'input>,
 ) -> Result<Rc<ast::Body<'input>>, Vec<SemanticError<'input>>> {
@@ -70,6 +132,55 @@ fn empty_body<'input: 'context, 'context>(
     }))
 }
 
+/// Runs `f` and, if it fails, records the errors in the context and continues with the `poisoned` node.
+/// Errors from a node that touched a poisoned variable are dropped as cascades of the original error.
+fn recover<'input: 'context, 'context, T>(
+    context: &'context Context<'input>,
+    f: impl FnOnce() -> Result<T, Vec<SemanticError<'input>>>,
+    poisoned: impl FnOnce() -> Result<T, Vec<SemanticError<'input>>>,
+) -> Result<T, Vec<SemanticError<'input>>> {
+    let depth = context.qual_stack.depth();
+    let non_null_len = context.non_null_vars.borrow().len();
+    let touched = context.poison_touched.replace(false);
+    let result = f();
+    let cascaded = context.poison_touched.replace(touched);
+    match result {
+        Ok(x) => {
+            *context.poison_touched.borrow_mut() |= cascaded;
+            Ok(x)
+        },
+        Err(es) => {
+            context.qual_stack.truncate(depth);
+            context.non_null_vars.borrow_mut().truncate(non_null_len);
+            if !cascaded {
+                context.semantic_errors.borrow_mut().extend(es);
+            }
+            poisoned()
+        },
+    }
+}
+
+fn poison_vars<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    vars: &[Rc<Var>],
+) {
+    context.poisoned_vars.borrow_mut().extend(vars.iter().cloned());
+}
+
+fn recovered_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TopStat<'input>>,
+) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
+    recover(
+        context,
+        || top_stat(context, node.clone()),
+        || Ok(Rc::new(ast::TopStat {
+            parsed: Some(node.clone()),
+            detail: Rc::new(ast::TopStatDetail::Error),
+        })),
+    )
+}
+
 pub fn top_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TopStat<'input>>,
@@ -77,10 +188,20 @@ pub fn top_stat<'input: 'context, 'context>(
     match node.kind.as_ref() {
         parser::ast::TopStatKind::VarBind { access_attr, sync_attr, var_bind } =>
             var_bind_top_stat(context, node.clone(), access_attr.clone(), sync_attr.clone(), var_bind.clone()),
-        parser::ast::TopStatKind::FnBind { access_attr, fn_bind } =>
-            fn_bind_top_stat(context, node.clone(), access_attr.clone(), fn_bind.clone()),
+        parser::ast::TopStatKind::FnBind { access_attr, ev_attr, fn_bind } =>
+            fn_bind_top_stat(context, node.clone(), access_attr.clone(), ev_attr.clone(), fn_bind.clone()),
+        parser::ast::TopStatKind::EnumBind { enum_bind } =>
+            enum_bind_top_stat(context, node.clone(), enum_bind.clone()),
+        parser::ast::TopStatKind::NewtyBind { newty_bind } =>
+            newty_bind_top_stat(context, node.clone(), newty_bind.clone()),
+        parser::ast::TopStatKind::ModBind { mod_bind } =>
+            mod_bind_top_stat(context, node.clone(), mod_bind.clone()),
+        parser::ast::TopStatKind::UseBind { use_bind } =>
+            use_bind_top_stat(context, node.clone(), use_bind.clone()),
         parser::ast::TopStatKind::Stat { stat } =>
             stat_top_stat(context, node.clone(), stat.clone()),
+        parser::ast::TopStatKind::Error =>
+            Err(vec![SemanticError::new(ErrorCode::Syntax, Some(node.slice), "Statement has syntax errors".to_owned())]),
     }
 }
 
@@ -94,6 +215,14 @@ fn var_bind_top_stat<'input: 'context, 'context>(
     let access_attr = self::access_attr(context, access_attr)?;
     let sync_attr = self::sync_attr(context, sync_attr)?;
     let var_bind = self::var_bind(context, var_bind)?;
+    let code = match access_attr.detail {
+        ast::AccessAttrDetail::None => ErrorCode::UnusedVariable,
+        ast::AccessAttrDetail::Pub => ErrorCode::UnusedPublicVar,
+    };
+    for (var, slice) in var_decl_idents(&var_bind.var_decl) {
+        context.bindings.borrow_mut().push((var.clone(), slice, code));
+        context.top_level_bindings.borrow_mut().push((var, slice));
+    }
     match access_attr.detail {
         ast::AccessAttrDetail::None => {
             let top_stat = Rc::new(ast::TopStat {
@@ -109,15 +238,15 @@ fn var_bind_top_stat<'input: 'context, 'context>(
         },
         ast::AccessAttrDetail::Pub => {
             if var_bind.vars.len() != 1 {
-                return Err(vec![SemanticError::new(Some(node.slice), "Public variable must not be tuple".to_owned())]);
+                return Err(vec![SemanticError::new(ErrorCode::InvalidPublicVar, Some(node.slice), "Public variable must not be tuple".to_owned())]);
             }
             let ast::ExprDetail::Term { term } = var_bind.expr.detail.as_ref()
                 else {
-                    return Err(vec![SemanticError::new(Some(node.slice), "Public variable should be assigned from a literal".to_owned())]);
+                    return Err(vec![SemanticError::new(ErrorCode::InvalidPublicVar, Some(node.slice), "Public variable should be assigned from a literal".to_owned())]);
                 };
             let ast::TermDetail::Literal { literal } = term.detail.as_ref()
                 else {
-                    return Err(vec![SemanticError::new(Some(node.slice), "Public variable should be assigned from a literal".to_owned())]);
+                    return Err(vec![SemanticError::new(ErrorCode::InvalidPublicVar, Some(node.slice), "Public variable should be assigned from a literal".to_owned())]);
                 };
             let var = &var_bind.vars[0];
             ValuedVar::new(context, var.qual.clone(), var.name.clone(), var.ty.borrow().clone(), literal.clone())
@@ -134,22 +263,33 @@ fn fn_bind_top_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TopStat<'input>>,
     access_attr: Option<Rc<parser::ast::AccessAttr<'input>>>,
+    ev_attr: Option<Rc<parser::ast::EvAttr<'input>>>,
     fn_bind: Rc<parser::ast::FnBind<'input>>,
 ) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
     let access_attr = self::access_attr(context, access_attr)?;
+    let ev_attr = self::ev_attr(context, ev_attr)?;
     let fn_bind = self::fn_bind(context, fn_bind)?;
     match access_attr.detail {
         ast::AccessAttrDetail::None => {
+            if ev_attr.detail == ast::EvAttrDetail::Custom {
+                return Err(vec![SemanticError::new(ErrorCode::InvalidEvent, ev_attr.parsed.as_ref().map(|x| x.slice), "Only public functions can be custom events".to_owned())]);
+            }
+            if let Some((var, slice)) = fn_var_ident(context, &fn_bind) {
+                context.bindings.borrow_mut().push((var.clone(), slice, ErrorCode::UnusedFunction));
+                context.top_level_bindings.borrow_mut().push((var, slice));
+            }
             Ok(Rc::new(ast::TopStat {
                 parsed: Some(node),
                 detail: Rc::new(ast::TopStatDetail::FnBind {
                     access_attr,
+                    ev_attr,
                     fn_bind,
                     ev: None,
                 }),
             }))
         },
         ast::AccessAttrDetail::Pub => {
+            check_ev_name(context, &ev_attr, &fn_bind)?;
             let tys =
                 fn_bind.fn_decl.var_decl.ty.ty_to_tys(context)
                 .map_err(|e| e.convert(None))?;
@@ -169,6 +309,7 @@ fn fn_bind_top_stat<'input: 'context, 'context>(
                 parsed: Some(node),
                 detail: Rc::new(ast::TopStatDetail::FnBind {
                     access_attr,
+                    ev_attr,
                     fn_bind,
                     ev: Some((ev, ev_stats)),
                 }),
@@ -177,6 +318,87 @@ fn fn_bind_top_stat<'input: 'context, 'context>(
     }
 }
 
+fn check_ev_name<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ev_attr: &ast::EvAttr<'input>,
+    fn_bind: &ast::FnBind<'input>,
+) -> Result<(), Vec<SemanticError<'input>>> {
+    let name = &fn_bind.fn_decl.ident.name;
+    let slice = fn_bind.fn_decl.ident.parsed.as_ref().map(|x| x.slice);
+    match (ev_attr.detail, Ev::get(context, name.clone())) {
+        (ast::EvAttrDetail::None, Err(_)) => {
+            let help = match Ev::closest(context, name) {
+                Some(ev) => format!("Did you mean `{}`? Add `custom` after `pub` to declare a custom event", ev.name),
+                None => "Add `custom` after `pub` to declare a custom event".to_owned(),
+            };
+            Err(vec![
+                SemanticError::new(ErrorCode::InvalidEvent, slice, format!("Function `{}` is not a Udon event", name))
+                .with_help(help)
+            ])
+        },
+        (ast::EvAttrDetail::Custom, Ok(_)) =>
+            Err(vec![SemanticError::new(ErrorCode::InvalidEvent, slice, format!("Function `{}` is a Udon event and cannot be marked `custom`", name))]),
+        _ =>
+            Ok(()),
+    }
+}
+
+fn enum_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TopStat<'input>>,
+    enum_bind: Rc<parser::ast::EnumBind<'input>>,
+) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
+    let enum_bind = self::enum_bind(context, enum_bind)?;
+    Ok(Rc::new(ast::TopStat {
+        parsed: Some(node),
+        detail: Rc::new(ast::TopStatDetail::EnumBind {
+            enum_bind,
+        }),
+    }))
+}
+
+fn newty_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TopStat<'input>>,
+    newty_bind: Rc<parser::ast::NewtyBind<'input>>,
+) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
+    let newty_bind = self::newty_bind(context, newty_bind)?;
+    Ok(Rc::new(ast::TopStat {
+        parsed: Some(node),
+        detail: Rc::new(ast::TopStatDetail::NewtyBind {
+            newty_bind,
+        }),
+    }))
+}
+
+fn mod_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TopStat<'input>>,
+    mod_bind: Rc<parser::ast::ModBind<'input>>,
+) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
+    let mod_bind = self::mod_bind(context, mod_bind)?;
+    Ok(Rc::new(ast::TopStat {
+        parsed: Some(node),
+        detail: Rc::new(ast::TopStatDetail::ModBind {
+            mod_bind,
+        }),
+    }))
+}
+
+fn use_bind_top_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TopStat<'input>>,
+    use_bind: Rc<parser::ast::UseBind<'input>>,
+) -> Result<Rc<ast::TopStat<'input>>, Vec<SemanticError<'input>>> {
+    let use_bind = self::use_bind(context, use_bind)?;
+    Ok(Rc::new(ast::TopStat {
+        parsed: Some(node),
+        detail: Rc::new(ast::TopStatDetail::UseBind {
+            use_bind,
+        }),
+    }))
+}
+
 fn stat_top_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TopStat<'input>>,
@@ -273,15 +495,42 @@ fn smooth_sync_attr<'input: 'context, 'context>(
     }))
 }
 
+fn ev_attr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Option<Rc<parser::ast::EvAttr<'input>>>,
+) -> Result<Rc<ast::EvAttr<'input>>, Vec<SemanticError<'input>>> {
+    match node {
+        Some(attr) =>
+            match &attr.attr.kind {
+                lexer::ast::KeywordKind::Custom =>
+                    custom_ev_attr(context, attr),
+                _ =>
+                    panic!("Illegal state"),
+            },
+        None =>
+            Ok(Rc::new(ast::EvAttr { parsed: None, detail: ast::EvAttrDetail::None })),
+    }
+}
+
+fn custom_ev_attr<'input: 'context, 'context>(
+    _context: &'context Context<'input>,
+    node: Rc<parser::ast::EvAttr<'input>>,
+) -> Result<Rc<ast::EvAttr<'input>>, Vec<SemanticError<'input>>> {
+    Ok(Rc::new(ast::EvAttr {
+        parsed: Some(node),
+        detail: ast::EvAttrDetail::Custom,
+    }))
+}
+
 pub fn var_bind<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::VarBind<'input>>,
 ) -> Result<Rc<ast::VarBind<'input>>, Vec<SemanticError<'input>>> {
     let var_decl = var_decl(context, node.var_decl.clone())?;
-    let expr = expr(context, node.expr.clone())?;
-    let mut vars = var_decl.vars.iter().cloned().collect();
-    infer(context, &mut vars, expr.ty.clone())
-        .map_err(|e| e.convert(Some(node.slice)))?;
+    let expr =
+        var_bind_expr(context, node.clone(), var_decl.clone())
+        .inspect_err(|_| poison_vars(context, &var_decl.vars))?;
+    shadowing_warnings(context, &var_decl);
     Ok(Rc::new(ast::VarBind {
         parsed: Some(node),
         var_decl: var_decl.clone(),
@@ -290,6 +539,61 @@ pub fn var_bind<'input: 'context, 'context>(
     }))
 }
 
+fn var_decl_idents<'input>(
+    var_decl: &ast::VarDecl<'input>,
+) -> Vec<(Rc<Var>, &'input str)> {
+    match var_decl.detail.as_ref() {
+        ast::VarDeclDetail::SingleDecl { mut_attr: _, ident, ty_expr: _, var } =>
+            ident.parsed.iter().map(|x| (var.clone(), x.slice)).collect(),
+        ast::VarDeclDetail::TupleDecl { var_decls } =>
+            var_decls.iter().flat_map(|x| var_decl_idents(x)).collect(),
+    }
+}
+
+fn fn_var_ident<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    fn_bind: &ast::FnBind<'input>,
+) -> Option<(Rc<Var>, &'input str)> {
+    let var = Var::get(context, fn_bind.fn_stats.qual.to_key(), fn_bind.fn_stats.name.clone()).ok()?;
+    let ident = fn_bind.fn_decl.ident.parsed.as_ref()?;
+    Some((var, ident.slice))
+}
+
+fn shadowing_warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    var_decl: &ast::VarDecl<'input>,
+) {
+    let visible_quals = context.qual_stack.iter().collect::<Vec<_>>();
+    for (var, slice) in var_decl_idents(var_decl) {
+        let top_level_bindings = context.top_level_bindings.borrow();
+        let shadowed =
+            top_level_bindings.iter()
+            .find(|(x, _)| x.name == var.name && !Rc::ptr_eq(x, &var) && visible_quals.contains(&x.qual.to_key()));
+        if let Some((_, first)) = shadowed {
+            context.warning_store.push(Rc::new(
+                SemanticError::new(ErrorCode::ShadowedTopLevel, Some(slice), format!("Variable `{}` shadows a top-level binding", var.name))
+                .with_label(Some(first), "Top-level binding defined here".to_owned())
+            ));
+        }
+    }
+}
+
+fn var_bind_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::VarBind<'input>>,
+    var_decl: Rc<ast::VarDecl<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let expr = expr_expecting(context, node.expr.clone(), Some(var_decl.ty.clone()))?;
+    let expr = converted_expr(context, &expr, &var_decl.ty)?;
+    let mut vars = var_decl.vars.iter().cloned().collect::<VecDeque<_>>();
+    infer(context, &mut vars, expr.ty.clone())
+        .map_err(|e| e.convert(Some(node.slice)))?;
+    if !vars.is_empty() {
+        return Err(vec![SemanticError::new(ErrorCode::InferenceFailed, Some(node.slice), "Type inference not succeeded".to_owned())]);
+    }
+    Ok(expr)
+}
+
 fn infer<'input: 'context, 'context>(
     context: &'context Context<'input>,
     vars: &mut VecDeque<Rc<Var>>,
@@ -307,16 +611,22 @@ fn infer<'input: 'context, 'context>(
     else if ty.base_eq_with_name("tuple") {
         let ty_args = ty.args_as_tuple();
         if vars.len() < ty_args.len() {
-            return Err(ElementError::new("Type inference not succeeded".to_owned()));
+            return Err(ElementError::new(ErrorCode::InferenceFailed, "Type inference not succeeded".to_owned()));
         }
         for t in ty_args {
             let t = t.get_value(context)?;
-            infer(context, vars, t)?;
+            if t.base_eq_with_name("tuple") {
+                infer(context, vars, t)?;
+            }
+            else {
+                let mut var = vars.pop_front().into_iter().collect();
+                infer(context, &mut var, t)?;
+            }
         }
         Ok(())
     }
     else {
-        Err(ElementError::new("Type inference not succeeded".to_owned()))
+        Err(ElementError::new(ErrorCode::InferenceFailed, "Type inference not succeeded".to_owned()))
     }
 }
 
@@ -426,21 +736,39 @@ fn mut_mut_attr<'input: 'context, 'context>(
 pub fn fn_bind<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::FnBind<'input>>,
+) -> Result<Rc<ast::FnBind<'input>>, Vec<SemanticError<'input>>> {
+    fn_bind_stats(context, node.clone())
+    .inspect_err(|_| {
+        if let Ok(qual) = context.qual_stack.peek().get_value(context) {
+            if let Ok(ty) = Ty::get_from_name(context, "unknown") {
+                let var = Var::force_new(context, qual, node.fn_decl.ident.slice.to_owned(), ty, false, None);
+                poison_vars(context, &[var]);
+            }
+        }
+    })
+}
+
+fn fn_bind_stats<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::FnBind<'input>>,
 ) -> Result<Rc<ast::FnBind<'input>>, Vec<SemanticError<'input>>> {
     let fn_decl = fn_decl(context, node.fn_decl.clone())?;
     if fn_decl.var_decl.ty.args_as_tuple().len() != fn_decl.var_decl.vars.len() {
-        return Err(vec![SemanticError::new(fn_decl.var_decl.parsed.clone().map(|x| x.slice), "Function arguments cannot be tuple".to_owned())]);
+        return Err(vec![SemanticError::new(ErrorCode::InvalidArgument, fn_decl.var_decl.parsed.clone().map(|x| x.slice), "Function arguments cannot be tuple".to_owned())]);
     }
     let qual =
         context.qual_stack.peek().get_value(context)
         .map_err(|e| e.convert(None))?;
-    let stats_block = stats_block(context, node.stats_block.clone(), Scope::Fn(context.fn_stats_store.next_id()))?;
+    let ret_ty =
+        fn_decl.ty_expr.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    let stats_block = stats_block_expecting(context, node.stats_block.clone(), Scope::Fn(context.fn_stats_store.next_id()), Some(ret_ty.clone()))?;
     let fn_stats =
         FnStats::new_or_get(
             context,
             qual,
             fn_decl.ident.name.clone(),
-            fn_decl.ty_expr.ty.clone(),
+            ret_ty,
             fn_decl.var_decl.vars.clone(),
             stats_block.clone(),
         )
@@ -471,11 +799,403 @@ pub fn fn_decl<'input: 'context, 'context>(
     }))
 }
 
+pub fn enum_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::EnumBind<'input>>,
+) -> Result<Rc<ast::EnumBind<'input>>, Vec<SemanticError<'input>>> {
+    let ident = self::ident(context, node.ident.clone())?;
+    let members =
+        node.members.iter()
+        .map(|x| self::ident(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, m) in members.iter().enumerate() {
+        if let Some(first) = members.iter().take(i).find(|x| x.name == m.name) {
+            return Err(vec![
+                SemanticError::new(ErrorCode::Duplicated, m.parsed.clone().map(|x| x.slice), format!("Enum member `{}` is duplicated", m.name))
+                .with_label(first.parsed.clone().map(|x| x.slice), "First defined here".to_owned())
+            ]);
+        }
+    }
+
+    let qual =
+        context.qual_stack.peek().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    let logical_name = format!("enum[{}{}]", qual.qualify_logical_name(">"), ident.name);
+    let base =
+        BaseTy::new(context, qual.clone(), ident.name.clone(), logical_name.clone())
+        .map_err(|e| e.convert(Some(node.ident.slice)))?;
+    let ty =
+        Ty::new_strict(
+            context,
+            base,
+            Vec::new(),
+            logical_name,
+            Some(TyInstance::Single { elem_name: None, ty_name: "SystemInt32".to_owned() }),
+            vec![TyLogicalKey::new("SystemObject".to_owned())],
+        )
+        .map_err(|e| e.convert(Some(node.ident.slice)))?;
+    let member_qual = qual.new_or_get_pushed_qual(context, ident.name.clone());
+    let vars =
+        members.iter().enumerate()
+        .map(|(i, m)| {
+            let literal =
+                Literal::new(context, i.to_string(), ty.clone())
+                .map_err(|e| e.convert(m.parsed.clone().map(|x| x.slice)))?;
+            Ok(Var::force_new(
+                context,
+                member_qual.clone(),
+                m.name.clone(),
+                ty.clone(),
+                false,
+                Some(DataLabel::new(DataLabelKind::Literal(literal))),
+            ))
+        })
+        .collect::<Result<Vec<_>, Vec<_>>>()?;
+    Ok(Rc::new(ast::EnumBind {
+        parsed: Some(node),
+        ident,
+        members,
+        ty,
+        vars,
+    }))
+}
+
+pub fn newty_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::NewtyBind<'input>>,
+) -> Result<Rc<ast::NewtyBind<'input>>, Vec<SemanticError<'input>>> {
+    let ident = self::ident(context, node.ident.clone())?;
+    let ty_expr = self::ty_expr(context, node.ty_expr.clone())?;
+    let methods =
+        node.methods.iter()
+        .map(|x| self::ident(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if !ty_expr.ty.base_eq_with_name("type") {
+        return Err(vec![SemanticError::new(ErrorCode::NotAType, Some(node.ty_expr.slice), "Specified expression is not a type".to_owned())]);
+    }
+    let inner =
+        ty_expr.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(Some(node.ty_expr.slice)))?;
+    if inner.instance.is_none() {
+        return Err(vec![SemanticError::new(ErrorCode::TypeMismatch, Some(node.ty_expr.slice), format!("Type `{}` cannot be wrapped", inner.description()))]);
+    }
+
+    let qual =
+        context.qual_stack.peek().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    let logical_name = format!("newty[{}{}]", qual.qualify_logical_name(">"), ident.name);
+    let base =
+        BaseTy::new(context, qual, ident.name.clone(), logical_name.clone())
+        .map_err(|e| e.convert(Some(node.ident.slice)))?;
+    let ty =
+        Ty::new_strict(context, base, Vec::new(), logical_name, inner.instance.clone(), inner.parents.clone())
+        .map_err(|e| e.convert(Some(node.ident.slice)))?;
+    let inner_type =
+        Ty::new_or_get_type_from_key(context, inner.to_key())
+        .map_err(|e| e.convert(None))?;
+    let ty_type =
+        Ty::new_or_get_type_from_key(context, ty.to_key())
+        .map_err(|e| e.convert(None))?;
+
+    for m in &methods {
+        let slice = m.parsed.clone().map(|x| x.slice);
+        let sources =
+            [(&inner, &ty), (&inner_type, &ty_type)].into_iter()
+            .filter_map(|(from, to)|
+                NamedMethodsKey::new(from.to_key(), m.name.clone()).get_value(context).ok()
+                .map(|x| (x, to.clone()))
+            )
+            .collect::<Vec<_>>();
+        if sources.is_empty() {
+            return Err(vec![SemanticError::new(ErrorCode::NotFound, slice, format!("Method `{}` not found in `{}`", m.name, inner.description()))]);
+        }
+        for (named_methods, to) in sources {
+            let wrap = |x: &Rc<Ty>| if *x == inner { ty.clone() } else { x.clone() };
+            let wrapped =
+                named_methods.methods.iter()
+                .map(|x| Method::new(
+                    context,
+                    to.clone(),
+                    x.name.clone(),
+                    x.param_tys.iter().map(wrap).collect(),
+                    x.param_in_outs.clone(),
+                    x.real_name.clone(),
+                    x.param_real_names.clone(),
+                ))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.convert(slice))?;
+            NamedMethods::from_methods(context, wrapped)
+                .map_err(|e| e.convert(slice))?;
+        }
+    }
+
+    Ok(Rc::new(ast::NewtyBind {
+        parsed: Some(node),
+        ident,
+        ty_expr,
+        methods,
+        ty,
+    }))
+}
+
+pub fn mod_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::ModBind<'input>>,
+) -> Result<Rc<ast::ModBind<'input>>, Vec<SemanticError<'input>>> {
+    let ident = self::ident(context, node.ident.clone())?;
+    let qual =
+        context.qual_stack.peek().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    let path = format!("{}{}", qual.qualify_logical_name("/"), ident.name);
+    if let Some(first) = context.mod_paths.borrow().get(&path) {
+        return Err(vec![
+            SemanticError::new(ErrorCode::Duplicated, Some(node.ident.slice), format!("Module `{}` is duplicated", ident.name))
+            .with_label(Some(*first), "First defined here".to_owned())
+        ]);
+    }
+    context.mod_paths.borrow_mut().insert(path.clone(), node.ident.slice);
+
+    let parsed_top_stats = match &node.top_stats {
+        Some(x) => x.clone(),
+        None => {
+            let file_name = format!("{}.teuchi", path);
+            let Some(target) = context.mod_files.borrow_mut().remove(&file_name)
+                else {
+                    return Err(vec![SemanticError::new(ErrorCode::NotFound, Some(node.ident.slice), format!("Module file `{}` not found", file_name))]);
+                };
+            target.body.as_ref().map(|x| x.top_stats.clone()).unwrap_or_default()
+        },
+    };
+
+    context.qual_stack.push_scope(context, Scope::Qual(ident.name.clone()));
+    let top_stats =
+        parsed_top_stats.into_iter()
+        .filter(|x| !matches!(x.kind.as_ref(), parser::ast::TopStatKind::Error))
+        .map(|x| recovered_top_stat(context, x))
+        .collect::<Result<Vec<_>, _>>();
+    context.qual_stack.pop();
+
+    Ok(Rc::new(ast::ModBind {
+        parsed: Some(node),
+        ident,
+        top_stats: top_stats?,
+    }))
+}
+
+pub fn use_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::UseBind<'input>>,
+) -> Result<Rc<ast::UseBind<'input>>, Vec<SemanticError<'input>>> {
+    let idents =
+        node.idents.iter()
+        .map(|x| self::ident(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let detail = match node.kind.as_ref() {
+        parser::ast::UseBindKind::Single =>
+            Rc::new(ast::UseBindDetail::Single),
+        parser::ast::UseBindKind::Alias { as_keyword: _, ident } =>
+            Rc::new(ast::UseBindDetail::Alias { ident: self::ident(context, ident.clone())? }),
+        parser::ast::UseBindKind::Glob { op_code: _ } =>
+            Rc::new(ast::UseBindDetail::Glob),
+    };
+    let current =
+        context.qual_stack.peek().get_value(context)
+        .map_err(|e| e.convert(None))?;
+
+    if let ast::UseBindDetail::Glob = detail.as_ref() {
+        let qual = use_qual(context, &node, &idents)?;
+        context.qual_stack.add_glob(current.to_key(), qual.to_key());
+        return Ok(Rc::new(ast::UseBind {
+            parsed: Some(node),
+            idents,
+            detail,
+        }));
+    }
+
+    let (name, path) = idents.split_last().unwrap();
+    let qual = match (path.is_empty(), detail.as_ref()) {
+        (false, _) =>
+            use_qual(context, &node, path)?,
+        (true, ast::UseBindDetail::Alias { ident: _ }) =>
+            context.qual_stack.find_ok(|x|
+                if
+                    Var::get(context, x.clone(), name.name.clone()).is_ok() ||
+                    Ty::get(context, x.clone(), name.name.clone(), Vec::new()).is_ok() ||
+                    x.pushed_qual(name.name.clone()).get_value(context).is_ok()
+                {
+                    x.get_value(context)
+                }
+                else {
+                    Err(ElementError::new(ErrorCode::NotFound, String::new()))
+                }
+            )
+            .map_err(|e| e.convert(Some(node.idents[0].slice)))?
+            .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(node.idents[0].slice), format!("Specified item `{}` not found", name.name))])?,
+        (true, _) =>
+            return Err(vec![SemanticError::new(ErrorCode::InvalidImport, Some(node.slice), "Imported item must be qualified".to_owned())]),
+    };
+    let (alias, alias_slice) = match detail.as_ref() {
+        ast::UseBindDetail::Alias { ident } => (ident.name.clone(), ident.parsed.clone().map(|x| x.slice)),
+        _ => (name.name.clone(), name.parsed.clone().map(|x| x.slice)),
+    };
+
+    let var = Var::get(context, qual.to_key(), name.name.clone()).ok();
+    let ty = Ty::get(context, qual.to_key(), name.name.clone(), Vec::new()).ok();
+    let sub_qual = qual.get_pushed_qual(context, name.name.clone()).ok();
+    if var.is_none() && ty.is_none() && sub_qual.is_none() {
+        return Err(vec![SemanticError::new(
+            ErrorCode::NotFound,
+            name.parsed.clone().map(|x| x.slice),
+            format!("Specified item `{}{}` not found", qual.qualify_description("::"), name.name)
+        )]);
+    }
+    if
+        Var::get(context, current.to_key(), alias.clone()).is_ok() ||
+        Ty::get(context, current.to_key(), alias.clone(), Vec::new()).is_ok() ||
+        current.get_pushed_qual(context, alias.clone()).is_ok()
+    {
+        return Err(vec![SemanticError::new(ErrorCode::Ambiguous, alias_slice, format!("Imported name `{}` is ambiguous with an existing item", alias))]);
+    }
+
+    if let Some(var) = var {
+        Var::force_new(
+            context,
+            current.clone(),
+            alias.clone(),
+            var.ty.borrow().clone(),
+            var.mut_attr,
+            Some(DataLabel::new(DataLabelKind::Var(var.clone()))),
+        );
+    }
+    if let Some(ty) = ty {
+        Ty::new_alias(context, TyKey::new(current.to_key(), alias.clone(), Vec::new()), &ty)
+        .map_err(|e| e.convert(alias_slice))?;
+    }
+    if let Some(sub_qual) = sub_qual {
+        Qual::new_alias(context, current.to_key().pushed_qual(alias.clone()), &sub_qual)
+        .map_err(|e| e.convert(alias_slice))?;
+    }
+
+    Ok(Rc::new(ast::UseBind {
+        parsed: Some(node),
+        idents,
+        detail,
+    }))
+}
+
+fn use_qual<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: &Rc<parser::ast::UseBind<'input>>,
+    path: &[Rc<ast::Ident<'input>>],
+) -> Result<Rc<Qual>, Vec<SemanticError<'input>>> {
+    let (first, rest) = path.split_first().unwrap();
+    let mut qual =
+        context.qual_stack.find_ok(|x| x.pushed_qual(first.name.clone()).get_value(context))
+        .map_err(|e| e.convert(Some(node.idents[0].slice)))?
+        .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(node.idents[0].slice), format!("Specified qualifier `{}` not found", first.name))])?;
+    for (i, x) in rest.iter().enumerate() {
+        qual =
+            qual.get_pushed_qual(context, x.name.clone())
+            .map_err(|e| e.convert(Some(node.idents[i + 1].slice)))?;
+    }
+    Ok(qual)
+}
+
+pub fn ty_bind<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::TyBind<'input>>,
+) -> Result<Rc<ast::TyBind<'input>>, Vec<SemanticError<'input>>> {
+    let ident = self::ident(context, node.ident.clone())?;
+    let ty_params =
+        node.ty_params.iter()
+        .map(|x| self::ident(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, p) in ty_params.iter().enumerate() {
+        if let Some(first) = ty_params.iter().take(i).find(|x| x.name == p.name) {
+            return Err(vec![
+                SemanticError::new(ErrorCode::Duplicated, p.parsed.clone().map(|x| x.slice), format!("Type parameter `{}` is duplicated", p.name))
+                .with_label(first.parsed.clone().map(|x| x.slice), "First defined here".to_owned())
+            ]);
+        }
+    }
+
+    let qual =
+        context.qual_stack.peek().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    let unknown =
+        Ty::get_from_name(context, "unknown")
+        .map_err(|e| e.convert(None))?;
+    let param_names = ty_params.iter().map(|x| x.name.clone()).collect::<Vec<_>>();
+    let (ty_expr, inner) = alias_ty_expr(context, &param_names, vec![unknown; param_names.len()], node.ty_expr.clone())?;
+
+    let ty =
+        if ty_params.is_empty() {
+            Ty::new_alias(context, TyKey::new(qual.to_key(), ident.name.clone(), Vec::new()), &inner)
+            .map_err(|e| e.convert(Some(node.ident.slice)))?
+        }
+        else {
+            let logical_name = TyAliasKey::new(qual.to_key(), ident.name.clone()).logical_name();
+            let base =
+                BaseTy::new(context, qual, ident.name.clone(), logical_name.clone())
+                .map_err(|e| e.convert(Some(node.ident.slice)))?;
+            let ty =
+                Ty::new_strict(context, base, Vec::new(), logical_name, None, Vec::new())
+                .map_err(|e| e.convert(Some(node.ident.slice)))?;
+            TyAlias::new(context, ty.clone(), param_names, node.ty_expr.clone())
+                .map_err(|e| e.convert(Some(node.ident.slice)))?;
+            ty
+        };
+    Ok(Rc::new(ast::TyBind {
+        parsed: Some(node),
+        ident,
+        ty_params,
+        ty_expr,
+        ty,
+    }))
+}
+
+fn alias_ty_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    param_names: &[String],
+    arg_tys: Vec<Rc<Ty>>,
+    node: Rc<parser::ast::TyExpr<'input>>,
+) -> Result<(Rc<ast::TyExpr<'input>>, Rc<Ty>), Vec<SemanticError<'input>>> {
+    context.qual_stack.push_scope(context, Scope::Block(context.block_id_factory.next_id()));
+    let result = (|| {
+        let qual = context.qual_stack.peek();
+        for (name, ty) in param_names.iter().zip(arg_tys.iter()) {
+            Ty::new_alias(context, TyKey::new(qual.clone(), name.clone(), Vec::new()), ty)
+                .map_err(|e| e.convert(Some(node.slice)))?;
+        }
+        let ty_expr = self::ty_expr(context, node.clone())?;
+        if !ty_expr.ty.base_eq_with_name("type") {
+            return Err(vec![SemanticError::new(ErrorCode::NotAType, Some(node.slice), "Specified expression is not a type".to_owned())]);
+        }
+        let inner =
+            ty_expr.ty.arg_as_type().get_value(context)
+            .map_err(|e| e.convert(Some(node.slice)))?;
+        Ok((ty_expr, inner))
+    })();
+    context.qual_stack.pop();
+    result
+}
+
 pub fn ty_expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TyExpr<'input>>,
 ) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
-    construct_ty_expr_tree(context, node)
+    let ty_expr = construct_ty_expr_tree(context, node.clone())?;
+    if ty_expr.ty.base_eq_with_name("type") {
+        let ty =
+            ty_expr.ty.arg_as_type().get_value(context)
+            .map_err(|e| e.convert(Some(node.slice)))?;
+        if let Ok(ty_alias) = TyAliasKey::new(ty.base.qual.to_key(), ty.base.name.clone()).get_value(context) {
+            if ty_alias.ty == ty {
+                return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(node.slice), format!("Type alias `{}` requires {} type arguments", ty.description(), ty_alias.ty_params.len()))]);
+            }
+        }
+    }
+    Ok(ty_expr)
 }
 
 fn construct_ty_expr_tree<'input: 'context, 'context>(
@@ -496,6 +1216,10 @@ fn construct_ty_expr_tree<'input: 'context, 'context>(
         let (op, expr) = match op.kind.as_ref() {
             parser::ast::TyOpKind::Access { op_code: _, ty_term } =>
                 access_op_ty_expr(context, node.clone(), ty_term.clone())?,
+            parser::ast::TyOpKind::Apply { ty_exprs } =>
+                apply_op_ty_expr(context, node.clone(), ty_exprs)?,
+            parser::ast::TyOpKind::Nullable { op_code: _ } =>
+                nullable_op_ty_expr(context)?,
         };
         ops.push_back(op);
         exprs.push_back(expr);
@@ -520,17 +1244,42 @@ fn access_op_ty_expr<'input: 'context, 'context>(
     Ok((op, expr))
 }
 
-fn hidden_unknown_ty_expr<'input: 'context, 'context>(
+fn apply_op_ty_expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
-) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
-    let term = Rc::new(ast::TyTerm {
-        parsed: None,
-        detail: Rc::new(ast::TyTermDetail::None),
-        ty: Ty::new_or_get_type_from_name(context, "unknown")
-            .map_err(|e| e.convert(None))?,
-    });
-    Ok(Rc::new(ast::TyExpr {
-        parsed: None,
+    node: Rc<parser::ast::TyExpr<'input>>,
+    ty_exprs: &[Rc<parser::ast::TyExpr<'input>>],
+) -> Result<(ast::TyOp, Rc<ast::TyExpr<'input>>), Vec<SemanticError<'input>>> {
+    let op = apply_ty_op(context)?;
+    let term = apply_ty_term(context, ty_exprs)?;
+    let expr = Rc::new(ast::TyExpr {
+        parsed: Some(node),
+        detail: Rc::new(ast::TyExprDetail::Term {
+            term: term.clone(),
+        }),
+        ty: term.ty.clone(),
+    });
+    Ok((op, expr))
+}
+
+fn nullable_op_ty_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> Result<(ast::TyOp, Rc<ast::TyExpr<'input>>), Vec<SemanticError<'input>>> {
+    let op = nullable_ty_op(context)?;
+    let expr = hidden_unknown_ty_expr(context)?;
+    Ok((op, expr))
+}
+
+fn hidden_unknown_ty_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
+    let term = Rc::new(ast::TyTerm {
+        parsed: None,
+        detail: Rc::new(ast::TyTermDetail::None),
+        ty: Ty::new_or_get_type_from_name(context, "unknown")
+            .map_err(|e| e.convert(None))?,
+    });
+    Ok(Rc::new(ast::TyExpr {
+        parsed: None,
         detail: Rc::new(ast::TyExprDetail::Term {
             term: term.clone(),
         }),
@@ -562,6 +1311,18 @@ fn access_ty_op<'input: 'context, 'context>(
     Ok(ast::TyOp::Access)
 }
 
+fn apply_ty_op<'input: 'context, 'context>(
+    _context: &'context Context<'input>,
+) -> Result<ast::TyOp, Vec<SemanticError<'input>>> {
+    Ok(ast::TyOp::Apply)
+}
+
+fn nullable_ty_op<'input: 'context, 'context>(
+    _context: &'context Context<'input>,
+) -> Result<ast::TyOp, Vec<SemanticError<'input>>> {
+    Ok(ast::TyOp::Nullable)
+}
+
 pub fn ty_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TyTerm<'input>>,
@@ -592,7 +1353,9 @@ fn eval_ty_ty_term<'input: 'context, 'context>(
         context.qual_stack.find_ok(|qual|
             Ty::new_or_get_type(context, qual.clone(), ident.name.clone(), Vec::new())
             .or(Ty::new_or_get_qual_from_key(context, qual.pushed_qual(ident.name.clone())))
-        ).ok_or(vec![SemanticError::new(Some(node.slice), format!("Specified qualifier `{}` not found", ident.name))])?;
+        )
+        .map_err(|e| e.convert(Some(node.slice)))?
+        .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(node.slice), format!("Specified qualifier `{}` not found", ident.name))])?;
     Ok(Rc::new(ast::TyTerm {
         parsed: Some(node),
         detail: Rc::new(ast::TyTermDetail::EvalTy {
@@ -602,6 +1365,26 @@ fn eval_ty_ty_term<'input: 'context, 'context>(
     }))
 }
 
+fn apply_ty_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty_exprs: &[Rc<parser::ast::TyExpr<'input>>],
+) -> Result<Rc<ast::TyTerm<'input>>, Vec<SemanticError<'input>>> {
+    let ty_exprs =
+        ty_exprs.iter()
+        .map(|x| self::ty_expr(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ty =
+        Ty::get_from_name(context, "unknown")
+        .map_err(|e| e.convert(None))?;
+    Ok(Rc::new(ast::TyTerm {
+        parsed: None,
+        detail: Rc::new(ast::TyTermDetail::ApplyTy {
+            ty_exprs,
+        }),
+        ty,
+    }))
+}
+
 fn eval_ty_access_ty_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::TyTerm<'input>>,
@@ -625,13 +1408,24 @@ pub fn stats_block<'input: 'context, 'context>(
     node: Rc<parser::ast::StatsBlock<'input>>,
     scope: Scope,
 ) -> Result<Rc<ast::StatsBlock<'input>>, Vec<SemanticError<'input>>> {
+    stats_block_expecting(context, node, scope, None)
+}
+
+fn stats_block_expecting<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::StatsBlock<'input>>,
+    scope: Scope,
+    expected: Option<Rc<Ty>>,
+) -> Result<Rc<ast::StatsBlock<'input>>, Vec<SemanticError<'input>>> {
+    unreachable_warnings(context, &node);
     context.qual_stack.push_scope(context, scope);
     let stats =
         node.stats.iter()
-        .map(|x| stat(context, x.clone()))
+        .filter(|x| !matches!(x.kind.as_ref(), parser::ast::StatKind::Error))
+        .map(|x| recovered_stat(context, x.clone()))
         .collect::<Result<_, _>>()?;
     let ret = match &node.ret {
-        Some(x) => expr(context, x.clone())?,
+        Some(x) => recover(context, || expr_expecting(context, x.clone(), expected), || poisoned_expr(context))?,
         None => hidden_unit_expr(context)?,
     };
     context.qual_stack.pop();
@@ -642,6 +1436,40 @@ pub fn stats_block<'input: 'context, 'context>(
     }))
 }
 
+fn unreachable_warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: &parser::ast::StatsBlock<'input>,
+) {
+    let Some(i) = node.stats.iter().position(|x| matches!(
+        x.kind.as_ref(),
+        parser::ast::StatKind::Return { .. } | parser::ast::StatKind::Continue { .. } | parser::ast::StatKind::Break { .. }
+    ))
+        else {
+            return;
+        };
+    let unreachable = match node.stats.get(i + 1) {
+        Some(x) => Some(x.slice),
+        None => node.ret.as_ref().map(|x| x.slice),
+    };
+    if let Some(slice) = unreachable {
+        context.warning_store.push(Rc::new(SemanticError::new(ErrorCode::UnreachableCode, Some(slice), "Unreachable code".to_owned())));
+    }
+}
+
+fn recovered_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Stat<'input>>,
+) -> Result<Rc<ast::Stat<'input>>, Vec<SemanticError<'input>>> {
+    recover(
+        context,
+        || stat(context, node.clone()),
+        || Ok(Rc::new(ast::Stat {
+            parsed: Some(node.clone()),
+            detail: Rc::new(ast::StatDetail::Error),
+        })),
+    )
+}
+
 pub fn stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Stat<'input>>,
@@ -657,8 +1485,12 @@ pub fn stat<'input: 'context, 'context>(
             var_bind_stat(context, node.clone(), var_bind.clone()),
         parser::ast::StatKind::FnBind { fn_bind } =>
             fn_bind_stat(context, node.clone(), fn_bind.clone()),
+        parser::ast::StatKind::TyBind { ty_bind } =>
+            ty_bind_stat(context, node.clone(), ty_bind.clone()),
         parser::ast::StatKind::Expr { expr } =>
             expr_stat(context, node.clone(), expr.clone()),
+        parser::ast::StatKind::Error =>
+            Err(vec![SemanticError::new(ErrorCode::Syntax, Some(node.slice), "Statement has syntax errors".to_owned())]),
     }
 }
 
@@ -705,6 +1537,9 @@ fn var_bind_stat<'input: 'context, 'context>(
     var_bind: Rc<parser::ast::VarBind<'input>>,
 ) -> Result<Rc<ast::Stat<'input>>, Vec<SemanticError<'input>>> {
     let var_bind = self::var_bind(context, var_bind)?;
+    for (var, slice) in var_decl_idents(&var_bind.var_decl) {
+        context.bindings.borrow_mut().push((var, slice, ErrorCode::UnusedVariable));
+    }
     Ok(Rc::new(ast::Stat {
         parsed: Some(node),
         detail: Rc::new(ast::StatDetail::VarBind {
@@ -719,6 +1554,9 @@ fn fn_bind_stat<'input: 'context, 'context>(
     fn_bind: Rc<parser::ast::FnBind<'input>>,
 ) -> Result<Rc<ast::Stat<'input>>, Vec<SemanticError<'input>>> {
     let fn_bind = self::fn_bind(context, fn_bind)?;
+    if let Some((var, slice)) = fn_var_ident(context, &fn_bind) {
+        context.bindings.borrow_mut().push((var, slice, ErrorCode::UnusedFunction));
+    }
     Ok(Rc::new(ast::Stat {
         parsed: Some(node),
         detail: Rc::new(ast::StatDetail::FnBind {
@@ -727,12 +1565,29 @@ fn fn_bind_stat<'input: 'context, 'context>(
     }))
 }
 
+fn ty_bind_stat<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Stat<'input>>,
+    ty_bind: Rc<parser::ast::TyBind<'input>>,
+) -> Result<Rc<ast::Stat<'input>>, Vec<SemanticError<'input>>> {
+    let ty_bind = self::ty_bind(context, ty_bind)?;
+    Ok(Rc::new(ast::Stat {
+        parsed: Some(node),
+        detail: Rc::new(ast::StatDetail::TyBind {
+            ty_bind,
+        }),
+    }))
+}
+
 fn expr_stat<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Stat<'input>>,
     expr: Rc<parser::ast::Expr<'input>>,
 ) -> Result<Rc<ast::Stat<'input>>, Vec<SemanticError<'input>>> {
     let expr = self::expr(context, expr)?;
+    if !["unit", "never", "unknown"].iter().any(|x| expr.ty.base_eq_with_name(x)) {
+        context.warning_store.push(Rc::new(SemanticError::new(ErrorCode::DiscardedValue, Some(node.slice), format!("Value of type `{}` is discarded", expr.ty.description()))));
+    }
     Ok(Rc::new(ast::Stat {
         parsed: Some(node),
         detail: Rc::new(ast::StatDetail::Expr {
@@ -745,16 +1600,26 @@ pub fn expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Expr<'input>>,
 ) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
-    construct_expr_tree(context, node)
+    expr_expecting(context, node, None)
+}
+
+fn expr_expecting<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Expr<'input>>,
+    expected: Option<Rc<Ty>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    construct_expr_tree(context, node, expected)
 }
 
 fn construct_expr_tree<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Expr<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
     let mut exprs = VecDeque::new();
     let mut ops = VecDeque::new();
-    let term = self::term(context, node.term.clone())?;
+    let expected = if node.ops.is_empty() { expected } else { None };
+    let term = term_expecting(context, node.term.clone(), expected)?;
     exprs.push_back(Rc::new(ast::Expr {
         parsed: Some(node.clone()),
         detail: Rc::new(ast::ExprDetail::Term {
@@ -769,8 +1634,8 @@ fn construct_expr_tree<'input: 'context, 'context>(
                 ty_access_op_expr(context, node.clone(), term.clone())?,
             parser::ast::OpKind::Access { op_code, term } =>
                 access_op_expr(context, node.clone(), op_code.clone(), term.clone())?,
-            parser::ast::OpKind::EvalFn { arg_exprs } =>
-                eval_fn_op_expr(context, node.clone(), arg_exprs)?,
+            parser::ast::OpKind::EvalFn { ty_exprs, arg_exprs } =>
+                eval_fn_op_expr(context, node.clone(), ty_exprs, arg_exprs)?,
             parser::ast::OpKind::EvalSpreadFn { expr } =>
                 eval_spread_fn_op_expr(context, node.clone(), expr.clone())?,
             parser::ast::OpKind::EvalKey { expr } =>
@@ -813,7 +1678,7 @@ fn access_op_expr<'input: 'context, 'context>(
     term: Rc<parser::ast::Term<'input>>,
 ) -> Result<(ast::Op, Rc<ast::Expr<'input>>), Vec<SemanticError<'input>>> {
     let op = access_op(context, op_code)?;
-    let term = self::term(context, term)?;
+    let term = self::access_term(context, term)?;
     let expr = Rc::new(ast::Expr {
         parsed: Some(node),
         detail: Rc::new(ast::ExprDetail::Term {
@@ -828,10 +1693,11 @@ fn access_op_expr<'input: 'context, 'context>(
 fn eval_fn_op_expr<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Expr<'input>>,
+    ty_exprs: &Vec<Rc<parser::ast::TyExpr<'input>>>,
     arg_exprs: &Vec<Rc<parser::ast::ArgExpr<'input>>>,
 ) -> Result<(ast::Op, Rc<ast::Expr<'input>>), Vec<SemanticError<'input>>> {
     let op = eval_fn_op(context)?;
-    let term = apply_fn_term(context, arg_exprs)?;
+    let term = apply_fn_term(context, ty_exprs, arg_exprs)?;
     let expr = Rc::new(ast::Expr {
         parsed: Some(node),
         detail: Rc::new(ast::ExprDetail::Term {
@@ -954,6 +1820,26 @@ fn hidden_unit_expr<'input: 'context, 'context>(
     }))
 }
 
+fn poisoned_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let term = Rc::new(ast::Term {
+        parsed: None,
+        detail: Rc::new(ast::TermDetail::None),
+        ty: Ty::get_from_name(context, "never")
+            .map_err(|e| e.convert(None))?,
+        data: RefCell::new(None),
+    });
+    Ok(Rc::new(ast::Expr {
+        parsed: None,
+        detail: Rc::new(ast::ExprDetail::Term {
+            term: term.clone(),
+        }),
+        ty: term.ty.clone(),
+        data: RefCell::new(None),
+    }))
+}
+
 pub fn ty_access_op<'input: 'context, 'context>(
     _context: &'context Context<'input>,
 ) -> Result<ast::Op, Vec<SemanticError<'input>>> {
@@ -1059,20 +1945,28 @@ fn assign_op<'input: 'context, 'context>(
 pub fn term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
+) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    term_expecting(context, node, None)
+}
+
+fn term_expecting<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
     match node.kind.as_ref() {
         parser::ast::TermKind::PrefixOp { op_code, term } =>
             prefix_op_term(context, node.clone(), op_code.clone(), term.clone()),
         parser::ast::TermKind::Block { stats } =>
-            block_term(context, node.clone(), stats.clone()),
+            block_term(context, node.clone(), stats.clone(), expected),
         parser::ast::TermKind::Paren { expr } =>
-            paren_term(context, node.clone(), expr.clone()),
+            paren_term(context, node.clone(), expr.clone(), expected),
         parser::ast::TermKind::Tuple { exprs } =>
-            tuple_term(context, node.clone(), exprs),
+            tuple_term(context, node.clone(), exprs, expected),
         parser::ast::TermKind::ArrayCtor { iter_expr } =>
             array_ctor_term(context, node.clone(), iter_expr.clone()),
         parser::ast::TermKind::Literal { literal } =>
-            literal_term(context, node.clone(), literal.clone()),
+            literal_term(context, node.clone(), literal.clone(), expected),
         parser::ast::TermKind::ThisLiteral { literal } =>
             this_literal_term(context, node.clone(), literal.clone()),
         parser::ast::TermKind::InterpolatedString { interpolated_string } =>
@@ -1080,9 +1974,11 @@ pub fn term<'input: 'context, 'context>(
         parser::ast::TermKind::EvalVar { ident } =>
             eval_var_term(context, node.clone(), ident.clone()),
         parser::ast::TermKind::LetInBind { var_bind, in_keyword: _, expr } =>
-            let_in_bind_term(context, node.clone(), var_bind.clone(), expr.clone()),
+            let_in_bind_term(context, node.clone(), var_bind.clone(), expr.clone(), expected),
         parser::ast::TermKind::If { if_keyword: _, condition, if_part, else_part } =>
-            if_term(context, node.clone(), condition.clone(), if_part.clone(), else_part.clone()),
+            if_term(context, node.clone(), condition.clone(), if_part.clone(), else_part.clone(), expected),
+        parser::ast::TermKind::Match { match_keyword: _, expr, match_arms } =>
+            match_term(context, node.clone(), expr.clone(), match_arms, expected),
         parser::ast::TermKind::While { while_keyword: _, condition, stats } =>
             while_term(context, node.clone(), condition.clone(), stats.clone()),
         parser::ast::TermKind::Loop { loop_keyword: _, stats } =>
@@ -1102,7 +1998,19 @@ fn ty_access_term<'input: 'context, 'context>(
         parser::ast::TermKind::EvalVar { ident } =>
             eval_var_ty_access_term(context, node.clone(), ident.clone()),
         _ =>
-            Err(vec![SemanticError::new(Some(node.slice), "Illegal use of type access op `::`".to_owned())])
+            Err(vec![SemanticError::new(ErrorCode::InvalidOperand, Some(node.slice), "Illegal use of type access op `::`".to_owned())])
+    }
+}
+
+fn access_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    match node.kind.as_ref() {
+        parser::ast::TermKind::EvalVar { ident } =>
+            eval_var_ty_access_term(context, node.clone(), ident.clone()),
+        _ =>
+            Err(vec![SemanticError::new(ErrorCode::InvalidOperand, Some(node.slice), "Illegal use of access op `.`".to_owned())])
     }
 }
 
@@ -1129,9 +2037,10 @@ fn block_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
     stats: Rc<parser::ast::StatsBlock<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
     let scope = Scope::Block(context.block_id_factory.next_id());
-    let stats = stats_block(context, stats, scope)?;
+    let stats = stats_block_expecting(context, stats, scope, expected)?;
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
         detail: Rc::new(ast::TermDetail::Block {
@@ -1146,8 +2055,9 @@ fn paren_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
     expr: Rc<parser::ast::Expr<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
-    let expr = self::expr(context, expr)?;
+    let expr = expr_expecting(context, expr, expected)?;
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
         detail: Rc::new(ast::TermDetail::Paren {
@@ -1162,10 +2072,18 @@ fn tuple_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
     exprs: &Vec<Rc<parser::ast::Expr<'input>>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let expected_tys =
+        expected
+        .filter(|x| x.base_eq_with_name("tuple") && x.args_as_tuple().len() == exprs.len())
+        .map(|x| x.ty_to_tys(context))
+        .transpose()
+        .map_err(|e| e.convert(Some(node.slice)))?;
     let exprs =
         exprs.iter()
-        .map(|x| expr(context, x.clone()))
+        .enumerate()
+        .map(|(i, x)| expr_expecting(context, x.clone(), expected_tys.as_ref().map(|x| x[i].clone())))
         .collect::<Result<Vec<_>, _>>()?;
     let data =
         exprs.iter()
@@ -1173,13 +2091,15 @@ fn tuple_term<'input: 'context, 'context>(
         .flat_map(|x| x.into_iter())
         .collect::<Vec<_>>();
     let data = if data.len() == 0 { None } else { Some(data) };
+    let ty =
+        Ty::tys_to_ty(context, &exprs.iter().map(|x| x.ty.clone()).collect())
+        .map_err(|e| e.convert(Some(node.slice)))?;
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
         detail: Rc::new(ast::TermDetail::Tuple {
             exprs,
         }),
-        ty: Ty::get_from_name(context, "tuple")
-            .map_err(|e| e.convert(None))?, // TODO
+        ty,
         data: RefCell::new(data),
     }))
 }
@@ -1208,8 +2128,16 @@ fn literal_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
     literal: Rc<lexer::ast::Literal<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
-    let literal = self::literal(context, literal)?;
+    let slice = node.slice;
+    let literal = match expected {
+        Some(x) =>
+            expected_literal(context, literal.clone(), self::literal(context, literal)?, &x)
+            .map_err(|e| e.convert(Some(slice)))?,
+        None =>
+            self::literal(context, literal)?,
+    };
     let data = Some(vec![DataLabel::new(DataLabelKind::Literal(literal.clone()))]);
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
@@ -1221,17 +2149,84 @@ fn literal_term<'input: 'context, 'context>(
     }))
 }
 
+fn expected_literal<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<lexer::ast::Literal<'input>>,
+    literal: Rc<Literal>,
+    expected: &Rc<Ty>,
+) -> Result<Rc<Literal>, ElementError> {
+    if expected_literal_fits(context, &node, &literal, expected)? {
+        Literal::new_or_get(context, literal.text.clone(), expected.clone())
+    }
+    else {
+        Ok(literal)
+    }
+}
+
+fn expected_literal_fits<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: &Rc<lexer::ast::Literal<'input>>,
+    literal: &Rc<Literal>,
+    expected: &Rc<Ty>,
+) -> Result<bool, ElementError> {
+    if expected.assignable_from(context, &literal.ty) {
+        return Ok(false);
+    }
+    let integer =
+        ["byte", "sbyte", "short", "ushort", "int", "uint", "long", "ulong"].into_iter()
+        .find(|x| expected.logical_eq_with_name(context, x));
+    let is_real =
+        ["float", "double", "decimal"].into_iter()
+        .any(|x| expected.logical_eq_with_name(context, x));
+    let in_range = |x| {
+        if integer_fits(x, literal) {
+            Ok(true)
+        }
+        else {
+            Err(Literal::out_of_range(node.slice, x))
+        }
+    };
+    match node.kind.as_ref() {
+        lexer::ast::LiteralKind::PureInteger { slice } |
+        lexer::ast::LiteralKind::DecInteger { slice } if !slice.ends_with(['U', 'u', 'L', 'l']) =>
+            integer.map_or(Ok(is_real), in_range),
+        lexer::ast::LiteralKind::HexInteger { slice } |
+        lexer::ast::LiteralKind::BinInteger { slice } if !slice.ends_with(['U', 'u', 'L', 'l']) =>
+            integer.map_or(Ok(false), in_range),
+        lexer::ast::LiteralKind::RealNumber { slice } if !slice.ends_with(['F', 'f', 'D', 'd', 'M', 'm']) =>
+            if is_real && expected.logical_eq_with_name(context, "float") && !literal.ty.logical_eq_with_name(context, "float") {
+                Err(Literal::out_of_range(node.slice, "float"))
+            }
+            else {
+                Ok(is_real)
+            },
+        _ =>
+            Ok(false),
+    }
+}
+
+fn integer_fits(ty_name: &str, literal: &Rc<Literal>) -> bool {
+    let value = Literal::parse_integer(&literal.text);
+    let range = Literal::integer_range(ty_name);
+    matches!((value, range), (Some(v), Some((min, max))) if min <= v && v <= max)
+}
+
 fn this_literal_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
     _literal: Rc<lexer::ast::Literal<'input>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let literal =
+        Literal::new_this(context)
+        .map_err(|e| e.convert(Some(node.slice)))?;
+    let data = Some(vec![DataLabel::new(DataLabelKind::Literal(literal.clone()))]);
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
-        detail: Rc::new(ast::TermDetail::ThisLiteral),
-        ty: Ty::get_from_name(context, "udon")
-            .map_err(|e| e.convert(None))?, // TODO
-        data: RefCell::new(None), // TODO
+        detail: Rc::new(ast::TermDetail::ThisLiteral {
+            literal: literal.clone(),
+        }),
+        ty: literal.ty.clone(),
+        data: RefCell::new(data),
     }))
 }
 
@@ -1261,15 +2256,28 @@ fn eval_var_term<'input: 'context, 'context>(
     let var =
         context.qual_stack.find_ok(|qual|
             Var::get(context, qual, ident.name.clone())
-        ).ok_or(vec![SemanticError::new(Some(node.slice), format!("Specified variable `{}` not found", ident.name))]);
+        )
+        .map_err(|e| e.convert(Some(node.slice)))?
+        .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(node.slice), format!("Specified variable `{}` not found", ident.name))]);
+    if let Ok(x) = &var {
+        context.used_var_ids.borrow_mut().insert(x.id);
+        if context.poisoned_vars.borrow().iter().any(|v| Rc::ptr_eq(v, x)) {
+            *context.poison_touched.borrow_mut() = true;
+        }
+    }
     let ty = match &var {
+        Ok(x) if context.non_null_vars.borrow().contains(x) =>
+            x.ty.borrow().non_null(context)
+            .map_err(|e| e.convert(Some(node.slice)))?,
         Ok(x) =>
             x.ty.borrow().clone(),
         Err(e) =>
             context.qual_stack.find_ok(|qual|
                 Ty::new_or_get_type(context, qual.clone(), ident.name.clone(), Vec::new())
                 .or(Ty::new_or_get_qual_from_key(context, qual.pushed_qual(ident.name.clone())))
-            ).ok_or(e.clone())?,
+            )
+            .map_err(|e| e.convert(Some(node.slice)))?
+            .ok_or(e.clone())?,
     };
     let data = var.clone().map(|x| vec![DataLabel::new(DataLabelKind::Var(x))]).ok();
     Ok(Rc::new(ast::Term {
@@ -1308,11 +2316,12 @@ fn let_in_bind_term<'input: 'context, 'context>(
     node: Rc<parser::ast::Term<'input>>,
     var_bind: Rc<parser::ast::VarBind<'input>>,
     expr: Rc<parser::ast::Expr<'input>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
     let scope = Scope::Block(context.block_id_factory.next_id());
     context.qual_stack.push_scope(context, scope);
     let var_bind = self::var_bind(context, var_bind)?;
-    let expr = self::expr(context, expr)?;
+    let expr = expr_expecting(context, expr, expected)?;
     context.qual_stack.pop();
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
@@ -1331,74 +2340,662 @@ fn if_term<'input: 'context, 'context>(
     condition: Rc<parser::ast::Expr<'input>>,
     if_part: Rc<parser::ast::StatsBlock<'input>>,
     else_part: Option<(Rc<lexer::ast::Keyword<'input>>, Rc<parser::ast::StatsBlock<'input>>)>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let null_check = null_check_var(context, &condition);
     let condition = expr(context, condition)?;
+    let bool_ty =
+        Ty::get_from_name(context, "bool")
+        .map_err(|e| e.convert(None))?;
+    if !bool_ty.assignable_from(context, &condition.ty) {
+        return Err(vec![SemanticError::new(ErrorCode::TypeMismatch, condition.parsed.clone().map(|x| x.slice), format!("Condition of type `{}` is not `bool`", condition.ty.description()))]);
+    }
     let if_scope = Scope::Block(context.block_id_factory.next_id());
-    let if_part = stats_block(context, if_part, if_scope)?;
+    let if_part = with_non_null(context, null_check.clone().filter(|x| x.1).map(|x| x.0), ||
+        stats_block_expecting(context, if_part, if_scope, expected.clone())
+    )?;
     let else_part = match else_part {
         Some((_, stats)) => {
             let else_scope = Scope::Block(context.block_id_factory.next_id());
-            Some(stats_block(context, stats, else_scope)?)
+            Some(with_non_null(context, null_check.filter(|x| !x.1).map(|x| x.0), ||
+                stats_block_expecting(context, stats, else_scope, expected.clone())
+            )?)
         },
         None => None,
     };
+
+    let ty = match &else_part {
+        Some(x) =>
+            if_branches_ty(context, node.clone(), &[if_part.ret.clone(), x.ret.clone()], expected)?,
+        None =>
+            Ty::get_from_name(context, "unit")
+            .map_err(|e| e.convert(None))?,
+    };
+    let result =
+        if ty.base_eq_with_name("unit") || ty.base_eq_with_name("never") {
+            None
+        }
+        else if ty.instance.is_some() {
+            Some(Var::new_tmp(context, ty.clone()))
+        }
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::NotStorable, Some(node.slice), format!("Result of `if` of type `{}` cannot be stored", ty.description()))]);
+        };
+    let data = result.clone().map(|x| vec![DataLabel::new(DataLabelKind::Var(x))]);
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
         detail: Rc::new(ast::TermDetail::If {
             condition,
-            if_part: if_part.clone(),
+            if_part,
             else_part,
+            result,
         }),
-        ty: if_part.ret.ty.clone(), // TODO
-        data: if_part.ret.data.clone(), // TODO
+        ty,
+        data: RefCell::new(data),
     }))
 }
 
-fn while_term<'input: 'context, 'context>(
+fn if_branches_ty<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
-    condition: Rc<parser::ast::Expr<'input>>,
-    stats: Rc<parser::ast::StatsBlock<'input>>,
+    rets: &[Rc<ast::Expr<'input>>],
+    expected: Option<Rc<Ty>>,
+) -> Result<Rc<Ty>, Vec<SemanticError<'input>>> {
+    let tys = rets.iter().map(|x| x.ty.clone()).collect::<Vec<_>>();
+    Ty::common_supertype(context, &tys)
+    .or_else(|e| expected_supertype(context, expected, &tys).ok_or(e))
+    .map_err(|e| match mismatched_branch(context, &tys) {
+        Some((i, ty)) =>
+            vec![SemanticError::new(
+                ErrorCode::TypeMismatch,
+                rets[i].parsed.clone().map(|x| x.slice),
+                format!("Branch of `if` has type `{}`, expected `{}`", rets[i].ty.description(), ty.description())
+            )],
+        None =>
+            e.convert(Some(node.slice)),
+    })
+}
+
+fn expected_supertype<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    expected: Option<Rc<Ty>>,
+    tys: &[Rc<Ty>],
+) -> Option<Rc<Ty>> {
+    expected.filter(|x|
+        x.is_dotnet_ty() &&
+        tys.iter().all(|t| t.base_eq_with_name("never") || x.assignable_from(context, t))
+    )
+}
+
+fn mismatched_branch<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    tys: &[Rc<Ty>],
+) -> Option<(usize, Rc<Ty>)> {
+    let mut branches =
+        tys.iter()
+        .enumerate()
+        .filter(|(_, x)| !x.base_eq_with_name("never"));
+    let (_, first) = branches.next()?;
+    branches
+    .find(|(_, x)| !first.assignable_from(context, x))
+    .map(|(i, _)| (i, first.clone()))
+}
+
+fn null_check_var<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    condition: &Rc<parser::ast::Expr<'input>>,
+) -> Option<(Rc<Var>, bool)> {
+    let [op] = condition.ops.as_slice()
+        else {
+            return None;
+        };
+    let parser::ast::OpKind::InfixOp { op_code, term } = op.kind.as_ref()
+        else {
+            return None;
+        };
+    let is_ne = match op_code.kind {
+        lexer::ast::OpCodeKind::Ne => true,
+        lexer::ast::OpCodeKind::Eq => false,
+        _ => return None,
+    };
+    let is_null = |x: &Rc<parser::ast::Term<'input>>|
+        matches!(x.kind.as_ref(), parser::ast::TermKind::Literal { literal } if matches!(literal.kind.as_ref(), lexer::ast::LiteralKind::Null { keyword: _ }));
+    let ident = match (condition.term.kind.as_ref(), term.kind.as_ref()) {
+        (parser::ast::TermKind::EvalVar { ident }, _) if is_null(term) => ident,
+        (_, parser::ast::TermKind::EvalVar { ident }) if is_null(&condition.term) => ident,
+        _ => return None,
+    };
+    let var =
+        context.qual_stack.find_ok(|qual|
+            Var::get(context, qual, ident.slice.to_owned())
+        )
+        .ok()??;
+    let is_nullable = var.ty.borrow().base_eq_with_name("nullable");
+    (!var.mut_attr && is_nullable).then_some((var, is_ne))
+}
+
+fn with_non_null<'input: 'context, 'context, T>(
+    context: &'context Context<'input>,
+    var: Option<Rc<Var>>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let Some(var) = var
+        else {
+            return f();
+        };
+    context.non_null_vars.borrow_mut().push(var);
+    let ret = f();
+    context.non_null_vars.borrow_mut().pop();
+    ret
+}
+
+fn match_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+    expr: Rc<parser::ast::Expr<'input>>,
+    match_arms: &Vec<Rc<parser::ast::MatchArm<'input>>>,
+    expected: Option<Rc<Ty>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
-    let condition = expr(context, condition)?;
-    let scope = Scope::Loop(context.loop_id_factory.next_id());
-    let stats = stats_block(context, stats, scope)?;
+    let expr = self::expr(context, expr)?;
+    if expr.ty.instance.is_none() {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, expr.parsed.clone().map(|x| x.slice), format!("Cannot match a value of type `{}`", expr.ty.description()))]);
+    }
+    let scrutinee = Var::new_tmp(context, expr.ty.clone());
+    let match_arms =
+        match_arms.iter()
+        .map(|x| match_arm(context, x.clone(), expr.ty.clone(), expected.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ty = match_arms_ty(context, node.clone(), &match_arms, expected)?;
+    let result =
+        if ty.base_eq_with_name("unit") || ty.base_eq_with_name("never") {
+            None
+        }
+        else if ty.instance.is_some() {
+            Some(Var::new_tmp(context, ty.clone()))
+        }
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::NotStorable, Some(node.slice), format!("Match result of type `{}` cannot be stored", ty.description()))]);
+        };
+    let data = result.clone().map(|x| vec![DataLabel::new(DataLabelKind::Var(x))]);
+
+    let rows = match_arms.iter().map(|x| vec![Some(x.pattern.clone())]).collect::<Vec<_>>();
+    if !is_exhaustive(context, std::slice::from_ref(&expr.ty), &rows) {
+        context.warning_store.push(Rc::new(SemanticError::new(ErrorCode::NonExhaustiveMatch, Some(node.slice), "Match is not exhaustive".to_owned())));
+    }
+
+    let jump_table = jump_table(context, &expr.ty, &match_arms);
     Ok(Rc::new(ast::Term {
         parsed: Some(node),
-        detail: Rc::new(ast::TermDetail::While {
-            condition,
-            stats,
+        detail: Rc::new(ast::TermDetail::Match {
+            expr,
+            match_arms,
+            scrutinee,
+            result,
+            jump_table,
         }),
-        ty: Ty::get_from_name(context, "unit")
-            .map_err(|e| e.convert(None))?,
-        data: RefCell::new(None),
+        ty,
+        data: RefCell::new(data),
     }))
 }
 
-fn loop_term<'input: 'context, 'context>(
+fn match_arms_ty<'input: 'context, 'context>(
     context: &'context Context<'input>,
     node: Rc<parser::ast::Term<'input>>,
-    stats: Rc<parser::ast::StatsBlock<'input>>,
-) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
-    let scope = Scope::Loop(context.loop_id_factory.next_id());
-    let stats = stats_block(context, stats, scope)?;
-    Ok(Rc::new(ast::Term {
+    match_arms: &Vec<Rc<ast::MatchArm<'input>>>,
+    expected: Option<Rc<Ty>>,
+) -> Result<Rc<Ty>, Vec<SemanticError<'input>>> {
+    let tys = match_arms.iter().map(|x| x.expr.ty.clone()).collect::<Vec<_>>();
+    Ty::common_supertype(context, &tys)
+    .or_else(|e| expected_supertype(context, expected, &tys).ok_or(e))
+    .map_err(|e| match mismatched_branch(context, &tys) {
+        Some((i, ty)) =>
+            vec![SemanticError::new(
+                ErrorCode::TypeMismatch,
+                match_arms[i].parsed.clone().map(|x| x.slice),
+                format!("Match arm has type `{}`, expected `{}`", match_arms[i].expr.ty.description(), ty.description())
+            )],
+        None =>
+            e.convert(Some(node.slice)),
+    })
+}
+
+pub fn match_arm<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::MatchArm<'input>>,
+    ty: Rc<Ty>,
+    expected: Option<Rc<Ty>>,
+) -> Result<Rc<ast::MatchArm<'input>>, Vec<SemanticError<'input>>> {
+    let pattern = self::pattern(context, node.pattern.clone(), ty, 0)?;
+    let expr = expr_expecting(context, node.expr.clone(), expected)?;
+    Ok(Rc::new(ast::MatchArm {
         parsed: Some(node),
-        detail: Rc::new(ast::TermDetail::Loop {
-            stats,
-        }),
-        ty: Ty::get_from_name(context, "unit")
-            .map_err(|e| e.convert(None))?,
-        data: RefCell::new(None),
+        pattern,
+        expr,
     }))
 }
 
-fn for_term<'input: 'context, 'context>(
+pub fn pattern<'input: 'context, 'context>(
     context: &'context Context<'input>,
-    node: Rc<parser::ast::Term<'input>>,
-    for_binds: &Vec<(Rc<lexer::ast::Keyword<'input>>, Rc<parser::ast::ForBind<'input>>)>,
-    stats: Rc<parser::ast::StatsBlock<'input>>,
-) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+    index: usize,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    match node.kind.as_ref() {
+        parser::ast::PatternKind::Wildcard { op_code: _ } =>
+            wildcard_pattern(context, node.clone(), ty),
+        parser::ast::PatternKind::Literal { literal } =>
+            literal_pattern(context, node.clone(), ty, index, literal.clone()),
+        parser::ast::PatternKind::Range { left, right } =>
+            range_pattern(context, node.clone(), ty, index, left.clone(), right.clone()),
+        parser::ast::PatternKind::Tuple { patterns } =>
+            tuple_pattern(context, node.clone(), ty, index, patterns),
+        parser::ast::PatternKind::EvalVar { idents } =>
+            eval_var_pattern(context, node.clone(), ty, index, idents),
+    }
+}
+
+fn wildcard_pattern<'input: 'context, 'context>(
+    _context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    Ok(Rc::new(ast::Pattern {
+        parsed: Some(node),
+        detail: Rc::new(ast::PatternDetail::Wildcard),
+        ty,
+    }))
+}
+
+fn literal_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+    index: usize,
+    literal: Rc<lexer::ast::Literal<'input>>,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    let literal = self::literal(context, literal)?;
+    if !ty.assignable_from(context, &literal.ty) {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Pattern of type `{}` cannot match a value of type `{}`", literal.ty.description(), ty.description()))]);
+    }
+    let test = pattern_test(context, node.clone(), &ty, index, "op_Equality", literal.clone())?;
+    Ok(Rc::new(ast::Pattern {
+        parsed: Some(node),
+        detail: Rc::new(ast::PatternDetail::Literal {
+            literal,
+            test,
+        }),
+        ty,
+    }))
+}
+
+fn range_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+    index: usize,
+    left: Rc<lexer::ast::Literal<'input>>,
+    right: Rc<lexer::ast::Literal<'input>>,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    let left = self::literal(context, left)?;
+    let right = self::literal(context, right)?;
+    for x in [&left, &right] {
+        if !ty.assignable_from(context, &x.ty) {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Pattern of type `{}` cannot match a value of type `{}`", x.ty.description(), ty.description()))]);
+        }
+    }
+    if let (Some(l), Some(r)) = (integer_value(&left), integer_value(&right)) {
+        if l > r {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Range pattern `{}..{}` matches no value", l, r))]);
+        }
+    }
+    let lower = pattern_test(context, node.clone(), &ty, index, "op_GreaterThanOrEqual", left.clone())?;
+    let upper = pattern_test(context, node.clone(), &ty, index, "op_LessThanOrEqual", right.clone())?;
+    Ok(Rc::new(ast::Pattern {
+        parsed: Some(node),
+        detail: Rc::new(ast::PatternDetail::Range {
+            left,
+            right,
+            lower,
+            upper,
+        }),
+        ty,
+    }))
+}
+
+fn tuple_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+    index: usize,
+    patterns: &Vec<Rc<parser::ast::Pattern<'input>>>,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    let tys =
+        if ty.base_eq_with_name("tuple") {
+            ty.ty_to_tys(context)
+            .map_err(|e| e.convert(Some(node.slice)))?
+        }
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Tuple pattern cannot match a value of type `{}`", ty.description()))]);
+        };
+    if tys.len() != patterns.len() {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Tuple pattern has {} elements, expected {}", patterns.len(), tys.len()))]);
+    }
+    let mut index = index;
+    let mut ps = Vec::new();
+    for (p, t) in patterns.iter().zip(tys) {
+        let count = t.instance.as_ref().map(|x| x.elem_count()).unwrap_or(0);
+        ps.push(pattern(context, p.clone(), t, index)?);
+        index += count;
+    }
+    Ok(Rc::new(ast::Pattern {
+        parsed: Some(node),
+        detail: Rc::new(ast::PatternDetail::Tuple {
+            patterns: ps,
+        }),
+        ty,
+    }))
+}
+
+fn eval_var_pattern<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: Rc<Ty>,
+    index: usize,
+    idents: &Vec<Rc<lexer::ast::Ident<'input>>>,
+) -> Result<Rc<ast::Pattern<'input>>, Vec<SemanticError<'input>>> {
+    let idents =
+        idents.iter()
+        .map(|x| ident(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let path = idents.iter().map(|x| x.name.clone()).collect::<Vec<_>>().join("::");
+    let (name, quals) = idents.split_last().unwrap();
+    let var =
+        context.qual_stack.find_ok(|qual| {
+            let qual = quals.iter().fold(qual, |acc, x| acc.pushed_qual(x.name.clone()));
+            Var::get(context, qual, name.name.clone())
+        })
+        .map_err(|e| e.convert(Some(node.slice)))?
+        .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(node.slice), format!("Specified variable `{}` not found", path))])?;
+    let literal = match var.actual_name.borrow().as_ref().map(|x| x.kind.clone()) {
+        Some(DataLabelKind::Literal(x)) => x,
+        _ => return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Pattern `{}` is not a constant", path))]),
+    };
+    if !ty.assignable_from(context, &var.ty.borrow()) {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Pattern of type `{}` cannot match a value of type `{}`", var.ty.borrow().description(), ty.description()))]);
+    }
+    let test = pattern_test(context, node.clone(), &ty, index, "op_Equality", literal.clone())?;
+    Ok(Rc::new(ast::Pattern {
+        parsed: Some(node),
+        detail: Rc::new(ast::PatternDetail::EvalVar {
+            idents,
+            var,
+            literal,
+            test,
+        }),
+        ty,
+    }))
+}
+
+fn pattern_test<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Pattern<'input>>,
+    ty: &Rc<Ty>,
+    index: usize,
+    name: &str,
+    operand: Rc<Literal>,
+) -> Result<Rc<ast::PatternTest>, Vec<SemanticError<'input>>> {
+    let Some(TyInstance::Single { elem_name: _, ty_name }) = &ty.instance
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidPattern, Some(node.slice), format!("Cannot compare a value of type `{}` in a pattern", ty.description()))]);
+        };
+    let method = extern_method(context, ty_name, name, vec![ty_name, ty_name])
+        .map_err(|e| e.convert(Some(node.slice)))?;
+    let cond = Var::new_tmp(
+        context,
+        Ty::get_from_name(context, "bool").map_err(|e| e.convert(Some(node.slice)))?
+    );
+    Ok(Rc::new(ast::PatternTest {
+        index,
+        method,
+        operand,
+        cond,
+    }))
+}
+
+fn extern_method<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty_name: &str,
+    name: &str,
+    in_ty_names: Vec<&str>,
+) -> Result<Rc<Method>, ElementError> {
+    let ty = Ty::new_or_get_type_from_key(context, TyLogicalKey::new(ty_name.to_owned()))?;
+    let in_tys =
+        in_ty_names.into_iter()
+        .map(|x| Ok(TyLogicalKey::new(x.to_owned()).get_value(context)?.to_key()))
+        .collect::<Result<Vec<_>, ElementError>>()?;
+    MethodKey {
+        ty: ty.to_key(),
+        name: name.to_owned(),
+        in_tys,
+    }.get_value(context)
+}
+
+fn integer_value(literal: &Rc<Literal>) -> Option<i64> {
+    Literal::parse_integer(&literal.text)?.try_into().ok()
+}
+
+fn is_exhaustive<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    tys: &[Rc<Ty>],
+    rows: &Vec<Vec<Option<Rc<ast::Pattern<'input>>>>>,
+) -> bool {
+    let Some((ty, rest_tys)) = tys.split_first()
+        else {
+            return !rows.is_empty();
+        };
+    if rows.is_empty() {
+        return false;
+    }
+
+    let is_wildcard = |p: &Option<Rc<ast::Pattern<'input>>>|
+        p.as_ref().is_none_or(|x| matches!(x.detail.as_ref(), ast::PatternDetail::Wildcard));
+
+    if ty.base_eq_with_name("tuple") {
+        let Ok(sub_tys) = ty.ty_to_tys(context)
+            else {
+                return false;
+            };
+        let expanded =
+            rows.iter()
+            .map(|row| {
+                let head = match row[0].as_ref().map(|x| x.detail.clone()).as_deref() {
+                    Some(ast::PatternDetail::Tuple { patterns }) =>
+                        patterns.iter().map(|x| Some(x.clone())).collect(),
+                    _ =>
+                        vec![None; sub_tys.len()],
+                };
+                head.into_iter().chain(row[1..].iter().cloned()).collect()
+            })
+            .collect();
+        let tys = sub_tys.into_iter().chain(rest_tys.iter().cloned()).collect::<Vec<_>>();
+        is_exhaustive(context, &tys, &expanded)
+    }
+    else if let Some(ctors) = finite_ctors(context, ty) {
+        ctors.iter().all(|c| {
+            let specialized =
+                rows.iter()
+                .filter(|row| is_wildcard(&row[0]) || ctor_text(&row[0]).as_ref() == Some(c))
+                .map(|row| row[1..].to_vec())
+                .collect();
+            is_exhaustive(context, rest_tys, &specialized)
+        })
+    }
+    else {
+        let defaulted =
+            rows.iter()
+            .filter(|row| is_wildcard(&row[0]))
+            .map(|row| row[1..].to_vec())
+            .collect();
+        is_exhaustive(context, rest_tys, &defaulted)
+    }
+}
+
+fn finite_ctors<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty: &Rc<Ty>,
+) -> Option<Vec<String>> {
+    if ty.logical_eq_with_name(context, "bool") {
+        return Some(vec!["true".to_owned(), "false".to_owned()]);
+    }
+    let qual = ty.base.qual.get_pushed_qual(context, ty.base.name.clone()).ok()?;
+    let ctors =
+        context.var_store.values()
+        .filter(|x| x.qual == qual && *x.ty.borrow() == *ty)
+        .filter_map(|x| match x.actual_name.borrow().as_ref().map(|x| x.kind.clone()) {
+            Some(DataLabelKind::Literal(l)) => Some(l.text.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if ctors.is_empty() { None } else { Some(ctors) }
+}
+
+fn ctor_text<'input>(pattern: &Option<Rc<ast::Pattern<'input>>>) -> Option<String> {
+    match pattern.as_ref()?.detail.as_ref() {
+        ast::PatternDetail::Literal { literal, test: _ } =>
+            Some(literal.text.clone()),
+        ast::PatternDetail::EvalVar { idents: _, var: _, literal, test: _ } =>
+            Some(literal.text.clone()),
+        _ =>
+            None,
+    }
+}
+
+fn jump_table<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty: &Rc<Ty>,
+    match_arms: &Vec<Rc<ast::MatchArm<'input>>>,
+) -> Option<Rc<ast::JumpTable>> {
+    const MIN_ENTRIES: usize = 3;
+    const MAX_SPAN: i64 = 256;
+
+    if ty.instance != Some(TyInstance::Single { elem_name: None, ty_name: "SystemInt32".to_owned() }) {
+        return None;
+    }
+
+    let mut values = Vec::<(i64, usize)>::new();
+    let mut fallback = None;
+    for (i, arm) in match_arms.iter().enumerate() {
+        let (min, max) = match arm.pattern.detail.as_ref() {
+            ast::PatternDetail::Wildcard => {
+                fallback = Some(i);
+                break;
+            },
+            ast::PatternDetail::Literal { literal, test: _ } |
+            ast::PatternDetail::EvalVar { idents: _, var: _, literal, test: _ } => {
+                let x = integer_value(literal)?;
+                (x, x)
+            },
+            ast::PatternDetail::Range { left, right, lower: _, upper: _ } =>
+                (integer_value(left)?, integer_value(right)?),
+            ast::PatternDetail::Tuple { patterns: _ } =>
+                return None,
+        };
+        if max - min >= MAX_SPAN {
+            return None;
+        }
+        for x in min..=max {
+            if values.iter().all(|(v, _)| *v != x) {
+                values.push((x, i));
+            }
+        }
+    }
+
+    let min = values.iter().map(|x| x.0).min()?;
+    let max = values.iter().map(|x| x.0).max()?;
+    let span = max - min + 1;
+    if values.len() < MIN_ENTRIES || span > MAX_SPAN || span > 2 * values.len() as i64 {
+        return None;
+    }
+    let entries =
+        (min..=max)
+        .map(|x| values.iter().find(|(v, _)| *v == x).map(|(_, i)| *i))
+        .collect();
+
+    let int = Ty::get_from_name(context, "int").ok()?;
+    let uint = Ty::get_from_name(context, "uint").ok()?;
+    let bool = Ty::get_from_name(context, "bool").ok()?;
+    let min_literal = Literal::new_or_get(context, min.to_string(), int.clone()).ok()?;
+    let max_literal = Literal::new_or_get(context, max.to_string(), int.clone()).ok()?;
+    let test = |name: &str, operand: Rc<Literal>| {
+        extern_method(context, "SystemInt32", name, vec!["SystemInt32", "SystemInt32"]).ok()
+        .map(|method| Rc::new(ast::PatternTest {
+            index: 0,
+            method,
+            operand,
+            cond: Var::new_tmp(context, bool.clone()),
+        }))
+    };
+    Some(Rc::new(ast::JumpTable {
+        entries,
+        fallback,
+        lower: test("op_GreaterThanOrEqual", min_literal.clone())?,
+        upper: test("op_LessThanOrEqual", max_literal)?,
+        sub: extern_method(context, "SystemInt32", "op_Subtraction", vec!["SystemInt32", "SystemInt32"]).ok()?,
+        to_uint: extern_method(context, "SystemConvert", "ToUInt32", vec!["SystemInt32"]).ok()?,
+        mul: extern_method(context, "SystemUInt32", "op_Multiplication", vec!["SystemUInt32", "SystemUInt32"]).ok()?,
+        add: extern_method(context, "SystemUInt32", "op_Addition", vec!["SystemUInt32", "SystemUInt32"]).ok()?,
+        stride: Literal::new_or_get(context, "8".to_owned(), uint.clone()).ok()?,
+        offset: Var::new_tmp(context, int),
+        addr: Var::new_tmp(context, uint),
+    }))
+}
+
+fn while_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+    condition: Rc<parser::ast::Expr<'input>>,
+    stats: Rc<parser::ast::StatsBlock<'input>>,
+) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let condition = expr(context, condition)?;
+    let scope = Scope::Loop(context.loop_id_factory.next_id());
+    let stats = stats_block(context, stats, scope)?;
+    Ok(Rc::new(ast::Term {
+        parsed: Some(node),
+        detail: Rc::new(ast::TermDetail::While {
+            condition,
+            stats,
+        }),
+        ty: Ty::get_from_name(context, "unit")
+            .map_err(|e| e.convert(None))?,
+        data: RefCell::new(None),
+    }))
+}
+
+fn loop_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+    stats: Rc<parser::ast::StatsBlock<'input>>,
+) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let scope = Scope::Loop(context.loop_id_factory.next_id());
+    let stats = stats_block(context, stats, scope)?;
+    Ok(Rc::new(ast::Term {
+        parsed: Some(node),
+        detail: Rc::new(ast::TermDetail::Loop {
+            stats,
+        }),
+        ty: Ty::get_from_name(context, "unit")
+            .map_err(|e| e.convert(None))?,
+        data: RefCell::new(None),
+    }))
+}
+
+fn for_term<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    node: Rc<parser::ast::Term<'input>>,
+    for_binds: &Vec<(Rc<lexer::ast::Keyword<'input>>, Rc<parser::ast::ForBind<'input>>)>,
+    stats: Rc<parser::ast::StatsBlock<'input>>,
+) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
     let for_binds =
         for_binds.iter()
         .map(|x| for_bind(context, x.1.clone()))
@@ -1445,29 +3042,38 @@ fn ty_expr_term<'input: 'context, 'context>(
     Ok(Rc::new(ast::Term {
         parsed: None,
         detail: Rc::new(ast::TermDetail::TyExpr {
-            ty_expr: te,
+            ty_expr: te.clone(),
         }),
-        ty: Ty::get_from_name(context, "type")
-            .map_err(|e| e.convert(None))?, // TODO
+        ty: te.ty.clone(),
         data: RefCell::new(None),
     }))
 }
 
 fn apply_fn_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
+    ty_exprs: &Vec<Rc<parser::ast::TyExpr<'input>>>,
     arg_exprs: &Vec<Rc<parser::ast::ArgExpr<'input>>>,
 ) -> Result<Rc<ast::Term<'input>>, Vec<SemanticError<'input>>> {
+    let ty_exprs =
+        ty_exprs.iter()
+        .map(|x| ty_expr(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ty_args =
+        ty_exprs.iter()
+        .map(|x| ty_arg_expr(context, x.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
     let args =
         arg_exprs.iter()
         .map(|x| arg_expr(context, x.clone()))
-        .collect::<Result<_, _>>()?;
+        .collect::<Result<Vec<_>, _>>()?;
     let ty =
         Ty::get_from_name(context, "unit")
         .map_err(|e| e.convert(None))?;
     Ok(Rc::new(ast::Term {
         parsed: None,
         detail: Rc::new(ast::TermDetail::ApplyFn {
-            args,
+            ty_exprs,
+            args: ty_args.into_iter().chain(args).collect(),
             as_fn: RefCell::new(None),
         }),
         ty,
@@ -1475,6 +3081,45 @@ fn apply_fn_term<'input: 'context, 'context>(
     }))
 }
 
+fn ty_arg_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty_expr: Rc<ast::TyExpr<'input>>,
+) -> Result<Rc<ast::ArgExpr<'input>>, Vec<SemanticError<'input>>> {
+    let slice = ty_expr.parsed.clone().map(|x| x.slice);
+    if !ty_expr.ty.base_eq_with_name("type") {
+        return Err(vec![SemanticError::new(ErrorCode::NotAType, slice, "Type argument must be a type".to_owned())]);
+    }
+    let arg_ty = ty_expr.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(slice))?;
+    let Some(TyInstance::Single { elem_name: _, ty_name }) = &arg_ty.instance
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, slice, format!("Type `{}` cannot be a type argument", arg_ty.description()))]);
+        };
+    let ty =
+        Ty::get_from_logical_name(context, "SystemType".to_owned())
+        .map_err(|e| e.convert(slice))?;
+    let literal =
+        Literal::new_or_get(context, ty_name.clone(), ty.clone())
+        .map_err(|e| e.convert(slice))?;
+    let data = Some(vec![DataLabel::new(DataLabelKind::Literal(literal.clone()))]);
+    let term = Rc::new(ast::Term {
+        parsed: None,
+        detail: Rc::new(ast::TermDetail::Literal { literal }),
+        ty: ty.clone(),
+        data: RefCell::new(data.clone()),
+    });
+    Ok(Rc::new(ast::ArgExpr {
+        parsed: None,
+        mut_attr: Rc::new(ast::MutAttr { parsed: None, detail: ast::MutAttrDetail::None }),
+        expr: Rc::new(ast::Expr {
+            parsed: None,
+            detail: Rc::new(ast::ExprDetail::Term { term }),
+            ty,
+            data: RefCell::new(data),
+        }),
+    }))
+}
+
 fn apply_spread_fn_term<'input: 'context, 'context>(
     context: &'context Context<'input>,
     expr: Rc<parser::ast::Expr<'input>>,
@@ -1918,6 +3563,10 @@ impl<'input: 'context, 'context>
         match &op {
             ast::TyOp::Access =>
                 access_ty_infix_op(context, parsed, left, op, right),
+            ast::TyOp::Apply =>
+                apply_ty_infix_op(context, parsed, left, op, right),
+            ast::TyOp::Nullable =>
+                nullable_ty_infix_op(context, parsed, left, op, right),
         }
     }
 }
@@ -1931,11 +3580,11 @@ fn access_ty_infix_op<'input: 'context, 'context>(
 ) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
     let ast::TyExprDetail::Term { term } = right.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `::` is not a term".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `::` is not a term".to_owned())]);
         };
     let ast::TyTermDetail::EvalTy { ident } = term.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `::` cannot be evaluated".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `::` cannot be evaluated".to_owned())]);
         };
 
     if left.ty.base_eq_with_name("qual") {
@@ -1971,10 +3620,107 @@ fn access_ty_infix_op<'input: 'context, 'context>(
         }))
     }
     else {
-        Err(vec![SemanticError::new(None, "Left side of `::` is not a qualifier or a type".to_owned())])
+        Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Left side of `::` is not a qualifier or a type".to_owned())])
     }
 }
 
+fn apply_ty_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::TyExpr<'input>>,
+    left: Rc<ast::TyExpr<'input>>,
+    op: ast::TyOp,
+    right: Rc<ast::TyExpr<'input>>,
+) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
+    let ast::TyExprDetail::Term { term } = right.detail.as_ref()
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of type application is not a term".to_owned())]);
+        };
+    let ast::TyTermDetail::ApplyTy { ty_exprs } = term.detail.as_ref()
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of type application cannot apply".to_owned())]);
+        };
+    let left_slice = left.parsed.clone().map(|x| x.slice);
+    if !left.ty.base_eq_with_name("type") {
+        return Err(vec![SemanticError::new(ErrorCode::NotAType, left_slice, "Left side of type application is not a type".to_owned())]);
+    }
+    let ty = left.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(left_slice))?;
+    let ty_alias =
+        TyAliasKey::new(ty.base.qual.to_key(), ty.base.name.clone()).get_value(context).ok()
+        .filter(|x| x.ty == ty)
+        .ok_or(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), format!("Type `{}` cannot take type arguments", ty.description()))])?;
+    if ty_alias.ty_params.len() != ty_exprs.len() {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), format!("Type alias `{}` requires {} type arguments", ty.description(), ty_alias.ty_params.len()))]);
+    }
+    let arg_tys =
+        ty_exprs.iter()
+        .map(|x|
+            if x.ty.base_eq_with_name("type") {
+                x.ty.arg_as_type().get_value(context)
+                .map_err(|e| e.convert(x.parsed.clone().map(|x| x.slice)))
+            }
+            else {
+                Err(vec![SemanticError::new(ErrorCode::NotAType, x.parsed.clone().map(|x| x.slice), "Specified expression is not a type".to_owned())])
+            }
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let key = TyKey::new(ty.base.qual.to_key(), ty.base.name.clone(), arg_tys.iter().map(|x| TyArg::Ty(x.to_key())).collect());
+    let applied = match key.clone().get_value(context) {
+        Ok(x) => x,
+        Err(_) => {
+            let (_, inner) = alias_ty_expr(context, &ty_alias.ty_params, arg_tys, ty_alias.ty_expr.clone())?;
+            Ty::new_alias(context, key, &inner)
+                .map_err(|e| e.convert(Some(parsed.slice)))?
+        },
+    };
+    let ty = Ty::new_or_get_type_from_key(context, applied.to_key())
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    Ok(Rc::new(ast::TyExpr {
+        parsed: Some(parsed),
+        detail: Rc::new(ast::TyExprDetail::InfixOp {
+            left: left.clone(),
+            op,
+            right: right.clone(),
+        }),
+        ty,
+    }))
+}
+
+fn nullable_ty_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::TyExpr<'input>>,
+    left: Rc<ast::TyExpr<'input>>,
+    op: ast::TyOp,
+    right: Rc<ast::TyExpr<'input>>,
+) -> Result<Rc<ast::TyExpr<'input>>, Vec<SemanticError<'input>>> {
+    let left_slice = left.parsed.clone().map(|x| x.slice);
+    if !left.ty.base_eq_with_name("type") {
+        return Err(vec![SemanticError::new(ErrorCode::NotAType, left_slice, "Left side of `?` is not a type".to_owned())]);
+    }
+    let ty = left.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(left_slice))?;
+    if ty.base_eq_with_name("nullable") {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidNullable, Some(parsed.slice), format!("Type `{}` is already nullable", ty.description()))]);
+    }
+    if !ty.is_reference_ty(context) {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidNullable, Some(parsed.slice), format!("Type `{}` is not a reference type and cannot be nullable", ty.description()))]);
+    }
+    let nullable = Ty::new_or_get_nullable_from_key(context, ty.to_key())
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    let ty = Ty::new_or_get_type_from_key(context, nullable.to_key())
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    Ok(Rc::new(ast::TyExpr {
+        parsed: Some(parsed),
+        detail: Rc::new(ast::TyExprDetail::InfixOp {
+            left: left.clone(),
+            op,
+            right: right.clone(),
+        }),
+        ty,
+    }))
+}
+
 impl<'input: 'context, 'context>
     ast::ExprTree<'input, 'context, ast::Op, parser::ast::Expr<'input>> for ast::Expr<'input>
 {
@@ -1992,14 +3738,58 @@ impl<'input: 'context, 'context>
         match &op {
             ast::Op::TyAccess =>
                 ty_access_infix_op(context, parsed, left, op, right),
+            ast::Op::Access =>
+                access_infix_op(context, parsed, left, op, right),
             ast::Op::EvalFn =>
                 eval_fn_infix_op(context, parsed, left, op, right),
+            ast::Op::CastOp =>
+                cast_infix_op(context, parsed, left, op, right),
+            ast::Op::Eq | ast::Op::Ne =>
+                eq_infix_op(context, parsed, left, op, right),
+            ast::Op::Coalescing =>
+                coalescing_infix_op(context, parsed, left, op, right),
+            ast::Op::Assign =>
+                assign_infix_op(context, parsed, left, op, right),
             _ =>
-                panic!("Not implemented")
+                Err(vec![SemanticError::new(ErrorCode::InvalidOperand, Some(parsed.slice), format!("Operator `{}` is not supported yet", op_code_str(&op)))]),
         }
     }
 }
 
+fn op_code_str(op: &ast::Op) -> &'static str {
+    match op {
+        ast::Op::TyAccess => "::",
+        ast::Op::Access => ".",
+        ast::Op::CoalescingAccess => "?.",
+        ast::Op::EvalFn => "()",
+        ast::Op::EvalSpreadFn => "(...)",
+        ast::Op::EvalKey => "[]",
+        ast::Op::CastOp => "as",
+        ast::Op::Mul => "*",
+        ast::Op::Div => "/",
+        ast::Op::Mod => "%",
+        ast::Op::Add => "+",
+        ast::Op::Sub => "-",
+        ast::Op::LeftShift => "<<",
+        ast::Op::RightShift => ">>",
+        ast::Op::Lt => "<",
+        ast::Op::Gt => ">",
+        ast::Op::Le => "<=",
+        ast::Op::Ge => ">=",
+        ast::Op::Eq => "==",
+        ast::Op::Ne => "!=",
+        ast::Op::BitAnd => "&",
+        ast::Op::BitXor => "^",
+        ast::Op::BitOr => "|",
+        ast::Op::And => "&&",
+        ast::Op::Or => "||",
+        ast::Op::Coalescing => "??",
+        ast::Op::RightPipeline => "|>",
+        ast::Op::LeftPipeline => "<|",
+        ast::Op::Assign => "=",
+    }
+}
+
 fn ty_access_infix_op<'input: 'context, 'context>(
     context: &'context Context<'input>,
     parsed: Rc<parser::ast::Expr<'input>>,
@@ -2009,11 +3799,11 @@ fn ty_access_infix_op<'input: 'context, 'context>(
 ) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
     let ast::ExprDetail::Term { term } = right.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `::` is not a term".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `::` is not a term".to_owned())]);
         };
     let ast::TermDetail::EvalVar { ident, var } = term.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `::` cannot be evaluated".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `::` cannot be evaluated".to_owned())]);
         };
 
     if left.ty.base_eq_with_name("qual") {
@@ -2022,6 +3812,7 @@ fn ty_access_infix_op<'input: 'context, 'context>(
         let ty = match &v {
             Some(x) => {
                 var.replace(Some(x.clone()));
+                context.used_var_ids.borrow_mut().insert(x.id);
                 x.ty.borrow().clone()
             },
             None =>
@@ -2051,12 +3842,22 @@ fn ty_access_infix_op<'input: 'context, 'context>(
         let ty = match &v {
             Some(x) => {
                 var.replace(Some(x.clone()));
+                context.used_var_ids.borrow_mut().insert(x.id);
                 x.ty.borrow().clone()
             },
-            None =>
+            None => match
                 Ty::new_or_get_type(context, qual.to_key(), ident.name.clone(), Vec::new())
                 .or(Ty::new_or_get_qual_from_key(context, qual.to_key().pushed_qual(ident.name.clone())))
-                .map_err(|e| e.convert(right.parsed.clone().map(|x| x.slice)))?,
+            {
+                Ok(x) => x,
+                Err(e) => {
+                    let Some(getter) = static_method_expr(context, parsed.clone(), left.clone(), &format!("get_{}", ident.name))
+                        else {
+                            return Err(e.convert(right.parsed.clone().map(|x| x.slice)));
+                        };
+                    return eval_getter(context, parsed, getter);
+                },
+            },
         };
         let data = v.map(|x| vec![DataLabel::new(DataLabelKind::Var(x))]);
         right.data.replace(data);
@@ -2072,10 +3873,131 @@ fn ty_access_infix_op<'input: 'context, 'context>(
         }))
     }
     else {
-        Err(vec![SemanticError::new(None, "Left side of `::` is not a qualifier or a type".to_owned())])
+        Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Left side of `::` is not a qualifier or a type".to_owned())])
     }
 }
 
+fn access_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    op: ast::Op,
+    right: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let ast::ExprDetail::Term { term } = right.detail.as_ref()
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `.` is not a term".to_owned())]);
+        };
+    let ast::TermDetail::EvalVar { ident, var: _ } = term.detail.as_ref()
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `.` cannot be evaluated".to_owned())]);
+        };
+
+    let receiver = left.data.borrow().clone().unwrap_or_default();
+    if left.ty.instance.is_none() || receiver.len() != 1 {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, left.parsed.clone().map(|x| x.slice), format!("Value of type `{}` cannot be a receiver", left.ty.description()))]);
+    }
+    if context.options.null_safety && left.ty.base_eq_with_name("nullable") {
+        return Err(vec![SemanticError::new(ErrorCode::PossiblyNull, left.parsed.clone().map(|x| x.slice), format!("Value of type `{}` may be null, check it against `null` before use", left.ty.description()))]);
+    }
+    let left_ty = left.ty.non_null(context)
+        .map_err(|e| e.convert(left.parsed.clone().map(|x| x.slice)))?;
+    let (key, is_getter) = match instance_methods_key(context, &left_ty, &ident.name) {
+        Some(x) => (x, false),
+        None =>
+            instance_methods_key(context, &left_ty, &format!("get_{}", ident.name))
+            .map(|x| (x, true))
+            .ok_or(vec![SemanticError::new(ErrorCode::NotFound, right.parsed.clone().map(|x| x.slice), format!("Method `{}` not found in `{}`", ident.name, left_ty.description()))])?,
+    };
+    let ty = Ty::get_method_from_key(context, key)
+        .map_err(|e| e.convert(None))?;
+    let method = Rc::new(ast::Expr {
+        parsed: Some(parsed.clone()),
+        detail: Rc::new(ast::ExprDetail::InfixOp {
+            left: left.clone(),
+            op,
+            right: right.clone(),
+        }),
+        ty,
+        data: RefCell::new(Some(receiver)),
+    });
+    if is_getter {
+        eval_getter(context, parsed, method)
+    }
+    else {
+        Ok(method)
+    }
+}
+
+fn eval_getter<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    getter: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let apply = apply_fn_term(context, &Vec::new(), &Vec::new())?;
+    let apply = Rc::new(ast::Expr {
+        parsed: None,
+        detail: Rc::new(ast::ExprDetail::Term { term: apply.clone() }),
+        ty: apply.ty.clone(),
+        data: RefCell::new(None),
+    });
+    eval_fn_infix_op(context, parsed, getter, ast::Op::EvalFn, apply)
+}
+
+fn instance_methods_key(
+    context: &Context,
+    ty: &Rc<Ty>,
+    name: &str,
+) -> Option<NamedMethodsKey> {
+    let key = NamedMethodsKey::new(ty.to_key(), name.to_owned());
+    if key.get_value(context).is_ok() {
+        return Some(key);
+    }
+    ty.parents.iter()
+    .filter_map(|x| x.get_value(context).ok())
+    .find_map(|x| instance_methods_key(context, &x, name))
+}
+
+fn static_method_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    name: &str,
+) -> Option<Rc<ast::Expr<'input>>> {
+    let key = NamedMethodsKey::new(left.ty.to_key(), name.to_owned());
+    key.get_value(context).ok()?;
+    let parent = left.ty.arg_as_type().get_value(context).ok()?;
+    let qual = parent.base.qual.get_pushed_qual(context, parent.base.name.clone()).ok()?;
+    let var = Var::get(context, qual.to_key(), name.to_owned()).ok()?;
+    let ty = Ty::get_method_from_key(context, key).ok()?;
+    let data = Some(vec![DataLabel::new(DataLabelKind::Var(var.clone()))]);
+    let term = Rc::new(ast::Term {
+        parsed: None,
+        detail: Rc::new(ast::TermDetail::EvalVar {
+            ident: Rc::new(ast::Ident { parsed: None, name: name.to_owned() }),
+            var: RefCell::new(Some(var)),
+        }),
+        ty: ty.clone(),
+        data: RefCell::new(data.clone()),
+    });
+    let right = Rc::new(ast::Expr {
+        parsed: None,
+        detail: Rc::new(ast::ExprDetail::Term { term }),
+        ty: ty.clone(),
+        data: RefCell::new(data.clone()),
+    });
+    Some(Rc::new(ast::Expr {
+        parsed: Some(parsed),
+        detail: Rc::new(ast::ExprDetail::InfixOp {
+            left,
+            op: ast::Op::TyAccess,
+            right,
+        }),
+        ty,
+        data: RefCell::new(data),
+    }))
+}
+
 fn eval_fn_infix_op<'input: 'context, 'context>(
     context: &'context Context<'input>,
     parsed: Rc<parser::ast::Expr<'input>>,
@@ -2085,17 +4007,32 @@ fn eval_fn_infix_op<'input: 'context, 'context>(
 ) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
     let ast::ExprDetail::Term { term } = right.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `eval fn` is not a term".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `eval fn` is not a term".to_owned())]);
         };
-    let ast::TermDetail::ApplyFn { args, as_fn } = term.detail.as_ref()
+    let ast::TermDetail::ApplyFn { ty_exprs, args, as_fn } = term.detail.as_ref()
         else {
-            return Err(vec![SemanticError::new(None, "Right side of `eval fn` cannot apply".to_owned())]);
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Right side of `eval fn` cannot apply".to_owned())]);
         };
 
-    if left.ty.base_eq_with_name("function") {
+    if left.ty.base_eq_with_name("type") {
+        let Some(ctor) = static_method_expr(context, parsed.clone(), left.clone(), "new")
+            else {
+                return Err(vec![SemanticError::new(ErrorCode::NotFound, Some(parsed.slice), format!("Constructor of type `{}` not found", left.ty.arg_as_type().description()))]);
+            };
+        eval_fn_infix_op(context, parsed, ctor, op, right)
+    }
+    else if left.ty.base_eq_with_name("function") {
+        if !ty_exprs.is_empty() {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), "Type arguments can only be passed to methods".to_owned())]);
+        }
         let key = left.ty.arg_as_function();
         let fn_stats = key.get_value(context)
             .map_err(|e| e.convert(Some(parsed.slice)))?;
+        let param_tys = fn_stats.vars.iter().map(|x| x.ty.borrow().clone()).collect::<Vec<_>>();
+        if let Some(args) = expected_args(context, args, &param_tys)? {
+            let right = apply_fn_expr_with_args(&right, term, ty_exprs, args);
+            return eval_fn_infix_op(context, parsed, left, op, right);
+        }
         let data =
             args.iter()
             .filter_map(|x| x.expr.data.borrow().clone())
@@ -2116,15 +4053,524 @@ fn eval_fn_infix_op<'input: 'context, 'context>(
         }))
     }
     else if left.ty.base_eq_with_name("method") {
+        if ty_exprs.len() > 1 {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), format!("Methods take at most one type argument, but {} were given", ty_exprs.len()))]);
+        }
         let in_tys = args.iter().map(|x| x.expr.ty.to_key()).collect();
-        let key = left.ty.most_compatible_method(context, in_tys)
-            .map_err(|e| e.convert(Some(parsed.slice)))?;
+        let key = match left.ty.most_compatible_method(context, in_tys) {
+            Ok(x) => x,
+            Err(e) => {
+                if let Some(args) = expected_method_args(context, &left.ty, args)? {
+                    let right = apply_fn_expr_with_args(&right, term, ty_exprs, args);
+                    return eval_fn_infix_op(context, parsed, left, op, right);
+                }
+                let nullable =
+                    args.iter()
+                    .find(|x| x.expr.ty.base_eq_with_name("nullable") || x.expr.ty.base_eq_with_name("nulltype"));
+                return Err(match nullable {
+                    Some(x) if context.options.null_safety =>
+                        vec![SemanticError::new(ErrorCode::PossiblyNull, x.expr.parsed.clone().map(|x| x.slice), format!("Argument of type `{}` may be null, check it against `null` before use", x.expr.ty.description()))],
+                    _ =>
+                        e.convert(Some(parsed.slice)),
+                });
+            },
+        };
         let m = key.get_value(context)
             .map_err(|e| e.convert(None))?;
-        let ty = Ty::tys_to_ty(context, &m.out_tys)
+        method_args(context, &m, args)?;
+        let out_tys = method_out_tys(context, parsed.clone(), &m, ty_exprs)?;
+        let ty = Ty::tys_to_ty(context, &m.to_result_order(&out_tys))
             .map_err(|e| e.convert(None))?;
+        if out_tys.iter().any(|x| x.instance.is_none()) {
+            return Err(vec![SemanticError::new(ErrorCode::NotStorable, Some(parsed.slice), format!("Result of method `{}` cannot be stored", m.name))]);
+        }
+        let data =
+            out_tys.iter()
+            .map(|x| DataLabel::new(DataLabelKind::Var(Var::new_tmp(context, x.clone()))))
+            .collect::<Vec<_>>();
+        let result = m.to_result_order(&data);
         as_fn.replace(Some(Rc::new(ast::AsFn::Method(m))));
-        right.data.replace(None); // TODO
+        right.data.replace(Some(data));
+        Ok(Rc::new(ast::Expr {
+            parsed: Some(parsed),
+            detail: Rc::new(ast::ExprDetail::InfixOp {
+                left: left.clone(),
+                op,
+                right: right.clone(),
+            }),
+            ty,
+            data: RefCell::new(Some(result)),
+        }))
+    }
+    else {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, None, "Left side of `eval fn` is not a function or a method".to_owned())]);
+    }
+}
+
+fn expected_method_args<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    method_ty: &Rc<Ty>,
+    args: &[Rc<ast::ArgExpr<'input>>],
+) -> Result<Option<Vec<Rc<ast::ArgExpr<'input>>>>, Vec<SemanticError<'input>>> {
+    let mut candidates = Vec::new();
+    for m in method_ty.args_as_method().iter().filter(|x| x.in_tys.len() == args.len()) {
+        let in_tys =
+            m.in_tys.iter()
+            .map(|x| x.get_value(context))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.convert(None))?;
+        if args.iter().zip(in_tys.iter()).all(|(a, t)| convertible_arg(context, a, t)) {
+            candidates.push(in_tys);
+        }
+    }
+    let better = |x: &Vec<Rc<Ty>>, y: &Vec<Rc<Ty>>|
+        x.iter().zip(y.iter()).all(|(x, y)| y.assignable_from(context, x) || y.implicitly_convertible_from(context, x));
+    let best =
+        candidates.iter()
+        .filter(|x| candidates.iter().all(|y| better(x, y)))
+        .collect::<Vec<_>>();
+    let [in_tys] = best.as_slice()
+        else {
+            return Ok(None);
+        };
+    expected_args(context, args, in_tys)
+}
+
+fn convertible_arg<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    arg: &Rc<ast::ArgExpr<'input>>,
+    ty: &Rc<Ty>,
+) -> bool {
+    if ty.assignable_from(context, &arg.expr.ty) {
+        return true;
+    }
+    if matches!(arg.mut_attr.detail, ast::MutAttrDetail::Mut) {
+        return false;
+    }
+    ty.implicitly_convertible_from(context, &arg.expr.ty) ||
+    expr_literal(&arg.expr).is_some_and(|(_, node, literal)|
+        expected_literal_fits(context, &node, &literal, ty).unwrap_or(false)
+    )
+}
+
+fn expected_args<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    args: &[Rc<ast::ArgExpr<'input>>],
+    tys: &[Rc<Ty>],
+) -> Result<Option<Vec<Rc<ast::ArgExpr<'input>>>>, Vec<SemanticError<'input>>> {
+    let mut changed = false;
+    let mut expected = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        let expr = match tys.get(i) {
+            Some(ty) if !matches!(arg.mut_attr.detail, ast::MutAttrDetail::Mut) =>
+                converted_expr(context, &arg.expr, ty)?,
+            _ =>
+                arg.expr.clone(),
+        };
+        changed |= !Rc::ptr_eq(&expr, &arg.expr);
+        expected.push(Rc::new(ast::ArgExpr {
+            parsed: arg.parsed.clone(),
+            mut_attr: arg.mut_attr.clone(),
+            expr,
+        }));
+    }
+    Ok(changed.then_some(expected))
+}
+
+fn converted_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    expr: &Rc<ast::Expr<'input>>,
+    ty: &Rc<Ty>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let expr = expected_literal_expr(context, expr, ty)?;
+    if !ty.implicitly_convertible_from(context, &expr.ty) {
+        return Ok(expr);
+    }
+    let Some(parsed) = expr.parsed.clone()
+        else {
+            return Ok(expr);
+        };
+    let Some((_, name)) =
+        [
+            ("sbyte", "ToSByte"),
+            ("byte", "ToByte"),
+            ("short", "ToInt16"),
+            ("ushort", "ToUInt16"),
+            ("int", "ToInt32"),
+            ("uint", "ToUInt32"),
+            ("long", "ToInt64"),
+            ("ulong", "ToUInt64"),
+            ("float", "ToSingle"),
+            ("double", "ToDouble"),
+            ("decimal", "ToDecimal"),
+        ]
+        .into_iter()
+        .find(|(x, _)| ty.logical_eq_with_name(context, x))
+        else {
+            return Ok(expr);
+        };
+    let convert =
+        TyLogicalKey::new("SystemConvert".to_owned()).get_value(context)
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    let ty_expr = hidden_type_expr(context, &convert)?;
+    let method = static_method_expr(context, parsed.clone(), ty_expr, name)
+        .ok_or(vec![SemanticError::new(ErrorCode::NotFound, Some(parsed.slice), format!("Conversion from `{}` to `{}` not found", expr.ty.description(), ty.description()))])?;
+    let apply = hidden_apply_fn_expr(context, vec![expr])?;
+    eval_fn_infix_op(context, parsed, method, ast::Op::EvalFn, apply)
+}
+
+fn expr_literal<'input>(
+    expr: &Rc<ast::Expr<'input>>,
+) -> Option<(Rc<parser::ast::Term<'input>>, Rc<lexer::ast::Literal<'input>>, Rc<Literal>)> {
+    let ast::ExprDetail::Term { term } = expr.detail.as_ref()
+        else {
+            return None;
+        };
+    let ast::TermDetail::Literal { literal } = term.detail.as_ref()
+        else {
+            return None;
+        };
+    let parsed = term.parsed.clone()?;
+    let parser::ast::TermKind::Literal { literal: node } = parsed.kind.as_ref()
+        else {
+            return None;
+        };
+    Some((parsed.clone(), node.clone(), literal.clone()))
+}
+
+fn expected_literal_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    expr: &Rc<ast::Expr<'input>>,
+    ty: &Rc<Ty>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let Some((parsed, node, _)) = expr_literal(expr)
+        else {
+            return Ok(expr.clone());
+        };
+    let term = literal_term(context, parsed, node, Some(ty.clone()))?;
+    if term.ty == expr.ty {
+        return Ok(expr.clone());
+    }
+    Ok(Rc::new(ast::Expr {
+        parsed: expr.parsed.clone(),
+        detail: Rc::new(ast::ExprDetail::Term {
+            term: term.clone(),
+        }),
+        ty: term.ty.clone(),
+        data: term.data.clone(),
+    }))
+}
+
+fn apply_fn_expr_with_args<'input>(
+    expr: &Rc<ast::Expr<'input>>,
+    term: &Rc<ast::Term<'input>>,
+    ty_exprs: &[Rc<ast::TyExpr<'input>>],
+    args: Vec<Rc<ast::ArgExpr<'input>>>,
+) -> Rc<ast::Expr<'input>> {
+    let term = Rc::new(ast::Term {
+        parsed: term.parsed.clone(),
+        detail: Rc::new(ast::TermDetail::ApplyFn {
+            ty_exprs: ty_exprs.to_vec(),
+            args,
+            as_fn: RefCell::new(None),
+        }),
+        ty: term.ty.clone(),
+        data: RefCell::new(None),
+    });
+    Rc::new(ast::Expr {
+        parsed: expr.parsed.clone(),
+        detail: Rc::new(ast::ExprDetail::Term { term }),
+        ty: expr.ty.clone(),
+        data: RefCell::new(None),
+    })
+}
+
+fn method_out_tys<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    m: &Rc<Method>,
+    ty_exprs: &[Rc<ast::TyExpr<'input>>],
+) -> Result<Vec<Rc<Ty>>, Vec<SemanticError<'input>>> {
+    let mut out_tys = m.out_tys.clone();
+    let ty_expr = match ty_exprs {
+        [] => return Ok(out_tys),
+        [x] => x,
+        _ => panic!("Illegal state"),
+    };
+    let Some(ret) = out_tys.last_mut().filter(|_| m.has_ret())
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), format!("Method `{}` has no result type to apply a type argument to", m.name))]);
+        };
+    let ty = ty_expr.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(None))?;
+    if !ret.assignable_from(context, &ty) {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidTypeArgument, Some(parsed.slice), format!("Type argument `{}` is not compatible with the result type `{}` of method `{}`", ty.description(), ret.description(), m.name))]);
+    }
+    *ret = ty;
+    Ok(out_tys)
+}
+
+fn method_args<'input: 'context, 'context>(
+    _context: &'context Context<'input>,
+    m: &Rc<Method>,
+    args: &[Rc<ast::ArgExpr<'input>>],
+) -> Result<(), Vec<SemanticError<'input>>> {
+    let params =
+        m.param_in_outs.iter()
+        .zip(m.param_real_names.iter())
+        .filter(|(io, _)| **io != MethodParamInOut::Out);
+    for ((io, name), arg) in params.zip(args.iter()) {
+        let slice = arg.parsed.clone().map(|x| x.slice);
+        let is_mut = matches!(arg.mut_attr.detail, ast::MutAttrDetail::Mut);
+        match io {
+            MethodParamInOut::InOut => {
+                if !is_mut {
+                    return Err(vec![SemanticError::new(ErrorCode::InvalidArgument, slice, format!("Argument for in/out parameter `{}` must be marked `mut`", name))]);
+                }
+                let is_mut_var = match arg.expr.detail.as_ref() {
+                    ast::ExprDetail::Term { term } => match term.detail.as_ref() {
+                        ast::TermDetail::EvalVar { ident: _, var } =>
+                            var.borrow().as_ref().is_some_and(|x| x.mut_attr),
+                        _ => false,
+                    },
+                    _ => false,
+                };
+                if !is_mut_var {
+                    return Err(vec![SemanticError::new(ErrorCode::InvalidArgument, slice, format!("Argument for in/out parameter `{}` must be a mutable variable", name))]);
+                }
+            },
+            _ => {
+                if is_mut {
+                    return Err(vec![SemanticError::new(ErrorCode::InvalidArgument, slice, format!("Argument for in parameter `{}` must not be marked `mut`", name))]);
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+fn eq_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    op: ast::Op,
+    right: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let (op_code, name) = match op {
+        ast::Op::Eq => ("==", "op_Equality"),
+        _ => ("!=", "op_Inequality"),
+    };
+    let is_null = |x: &Rc<ast::Expr<'input>>| x.ty.base_eq_with_name("nulltype") || x.ty.base_eq_with_name("nullable");
+    let (ty, args) =
+        if is_null(&left) || is_null(&right) {
+            let ty =
+                Ty::get_from_name(context, "object")
+                .map_err(|e| e.convert(None))?;
+            let args = vec![as_ty_expr(&left, ty.clone()), as_ty_expr(&right, ty.clone())];
+            (ty, args)
+        }
+        else {
+            (left.ty.clone(), vec![left.clone(), right.clone()])
+        };
+    let ty_expr = hidden_type_expr(context, &ty)?;
+    let method = static_method_expr(context, parsed.clone(), ty_expr, name)
+        .ok_or(vec![SemanticError::new(ErrorCode::InvalidOperand, Some(parsed.slice), format!("Operator `{}` is not defined for type `{}`", op_code, ty.description()))])?;
+    let apply = hidden_apply_fn_expr(context, args)?;
+    eval_fn_infix_op(context, parsed, method, ast::Op::EvalFn, apply)
+}
+
+fn coalescing_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    _op: ast::Op,
+    right: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let is_nullable = left.ty.base_eq_with_name("nullable");
+    if !is_nullable && (context.options.null_safety || !left.ty.is_reference_ty(context)) {
+        return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, left.parsed.clone().map(|x| x.slice), format!("Left side of `??` has non-nullable type `{}`", left.ty.description()))]);
+    }
+    if !left.ty.assignable_from(context, &right.ty) {
+        return Err(vec![SemanticError::new(ErrorCode::TypeMismatch, right.parsed.clone().map(|x| x.slice), format!("Right side of `??` has type `{}`, expected `{}`", right.ty.description(), left.ty.description()))]);
+    }
+    let ty =
+        if right.ty.base_eq_with_name("nullable") || right.ty.base_eq_with_name("nulltype") {
+            left.ty.clone()
+        }
+        else {
+            left.ty.non_null(context)
+            .map_err(|e| e.convert(None))?
+        };
+    let scrutinee = Var::new_tmp(context, left.ty.clone());
+    let test = null_test(context, parsed.clone())?;
+    let result = Var::new_tmp(context, ty.clone());
+    Ok(Rc::new(ast::Expr {
+        parsed: Some(parsed),
+        detail: Rc::new(ast::ExprDetail::Coalescing {
+            left,
+            right,
+            scrutinee,
+            test,
+            result: result.clone(),
+        }),
+        ty,
+        data: RefCell::new(Some(vec![DataLabel::new(DataLabelKind::Var(result))])),
+    }))
+}
+
+fn null_test<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+) -> Result<Rc<ast::PatternTest>, Vec<SemanticError<'input>>> {
+    let method = extern_method(context, "SystemObject", "op_Inequality", vec!["SystemObject", "SystemObject"])
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    let operand = Literal::new_null(context)
+        .map_err(|e| e.convert(Some(parsed.slice)))?;
+    let cond = Var::new_tmp(
+        context,
+        Ty::get_from_name(context, "bool").map_err(|e| e.convert(Some(parsed.slice)))?
+    );
+    Ok(Rc::new(ast::PatternTest {
+        index: 0,
+        method,
+        operand,
+        cond,
+    }))
+}
+
+fn as_ty_expr<'input>(
+    expr: &Rc<ast::Expr<'input>>,
+    ty: Rc<Ty>,
+) -> Rc<ast::Expr<'input>> {
+    Rc::new(ast::Expr {
+        parsed: expr.parsed.clone(),
+        detail: expr.detail.clone(),
+        ty,
+        data: expr.data.clone(),
+    })
+}
+
+fn hidden_type_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    ty: &Rc<Ty>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let ty =
+        Ty::new_or_get_type_from_key(context, ty.to_key())
+        .map_err(|e| e.convert(None))?;
+    let term = Rc::new(ast::Term {
+        parsed: None,
+        detail: Rc::new(ast::TermDetail::None),
+        ty: ty.clone(),
+        data: RefCell::new(None),
+    });
+    Ok(Rc::new(ast::Expr {
+        parsed: None,
+        detail: Rc::new(ast::ExprDetail::Term { term }),
+        ty,
+        data: RefCell::new(None),
+    }))
+}
+
+fn hidden_apply_fn_expr<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    args: Vec<Rc<ast::Expr<'input>>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let args =
+        args.into_iter()
+        .map(|x| Rc::new(ast::ArgExpr {
+            parsed: None,
+            mut_attr: Rc::new(ast::MutAttr { parsed: None, detail: ast::MutAttrDetail::None }),
+            expr: x,
+        }))
+        .collect();
+    let ty =
+        Ty::get_from_name(context, "unit")
+        .map_err(|e| e.convert(None))?;
+    let term = Rc::new(ast::Term {
+        parsed: None,
+        detail: Rc::new(ast::TermDetail::ApplyFn {
+            ty_exprs: Vec::new(),
+            args,
+            as_fn: RefCell::new(None),
+        }),
+        ty: ty.clone(),
+        data: RefCell::new(None),
+    });
+    Ok(Rc::new(ast::Expr {
+        parsed: None,
+        detail: Rc::new(ast::ExprDetail::Term { term }),
+        ty,
+        data: RefCell::new(None),
+    }))
+}
+
+fn assign_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    op: ast::Op,
+    right: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    let var = match left.detail.as_ref() {
+        ast::ExprDetail::Term { term } => match term.detail.as_ref() {
+            ast::TermDetail::EvalVar { ident: _, var } =>
+                var.borrow().clone(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(var) = var
+        else {
+            return Err(vec![SemanticError::new(ErrorCode::InvalidOperand, Some(parsed.slice), "Left side of `=` is not a variable".to_owned())]);
+        };
+    if !var.mut_attr {
+        return Err(vec![
+            SemanticError::new(ErrorCode::InvalidOperand, Some(parsed.slice), format!("Variable `{}` is not mutable", var.name))
+            .with_help("Declare it with `let mut` to assign to it".to_owned())
+        ]);
+    }
+    let ty = var.ty.borrow().clone();
+    let right = converted_expr(context, &right, &ty)?;
+    if !ty.assignable_from(context, &right.ty) {
+        return Err(vec![SemanticError::new(ErrorCode::TypeMismatch, Some(parsed.slice), format!("Value of type `{}` cannot be assigned to variable `{}` of type `{}`", right.ty.description(), var.name, ty.description()))]);
+    }
+    Ok(Rc::new(ast::Expr {
+        parsed: Some(parsed),
+        detail: Rc::new(ast::ExprDetail::InfixOp {
+            left: left.clone(),
+            op,
+            right: right.clone(),
+        }),
+        ty: Ty::get_from_name(context, "unit")
+            .map_err(|e| e.convert(None))?,
+        data: RefCell::new(None),
+    }))
+}
+
+fn cast_infix_op<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    parsed: Rc<parser::ast::Expr<'input>>,
+    left: Rc<ast::Expr<'input>>,
+    op: ast::Op,
+    right: Rc<ast::Expr<'input>>,
+) -> Result<Rc<ast::Expr<'input>>, Vec<SemanticError<'input>>> {
+    if !right.ty.base_eq_with_name("type") {
+        return Err(vec![SemanticError::new(ErrorCode::NotAType, None, "Right side of `as` is not a type".to_owned())]);
+    }
+    let ty = right.ty.arg_as_type().get_value(context)
+        .map_err(|e| e.convert(right.parsed.clone().map(|x| x.slice)))?;
+    let is_nullable = left.ty.base_eq_with_name("nullable") || left.ty.base_eq_with_name("nulltype");
+    if context.options.null_safety && is_nullable && !ty.base_eq_with_name("nullable") {
+        return Err(vec![
+            SemanticError::new(ErrorCode::PossiblyNull, Some(parsed.slice), format!("Value of type `{}` may be null and cannot be cast to `{}`", left.ty.description(), ty.description()))
+            .with_help("Check it with `if x != null` first, or give a default value with `??`".to_owned())
+        ]);
+    }
+
+    let same_instance =
+        ty.instance.is_some() && ty.instance == left.ty.instance &&
+        !(ty.is_wrapper_ty() && left.ty.is_wrapper_ty());
+    if ty.assignable_from(context, &left.ty) || same_instance {
         Ok(Rc::new(ast::Expr {
             parsed: Some(parsed),
             detail: Rc::new(ast::ExprDetail::InfixOp {
@@ -2133,10 +4579,10 @@ fn eval_fn_infix_op<'input: 'context, 'context>(
                 right: right.clone(),
             }),
             ty,
-            data: RefCell::new(None), // TODO
+            data: left.data.clone(),
         }))
     }
     else {
-        return Err(vec![SemanticError::new(None, "Left side of `eval fn` is not a function or a method".to_owned())]);
+        Err(vec![SemanticError::new(ErrorCode::TypeMismatch, Some(parsed.slice), format!("Cannot cast `{}` to `{}`", left.ty.description(), ty.description()))])
     }
 }
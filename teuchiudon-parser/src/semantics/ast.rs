@@ -38,12 +38,26 @@ pub enum TopStatDetail<'input> {
     },
     FnBind {
         access_attr: Rc<AccessAttr<'input>>,
+        ev_attr: Rc<EvAttr<'input>>,
         fn_bind: Rc<FnBind<'input>>,
         ev: Option<(Rc<elements::ev::Ev>, Rc<elements::ev_stats::EvStats<'input>>)>,
     },
+    EnumBind {
+        enum_bind: Rc<EnumBind<'input>>,
+    },
+    NewtyBind {
+        newty_bind: Rc<NewtyBind<'input>>,
+    },
+    ModBind {
+        mod_bind: Rc<ModBind<'input>>,
+    },
+    UseBind {
+        use_bind: Rc<UseBind<'input>>,
+    },
     Stat {
         stat: Rc<Stat<'input>>,
     },
+    Error,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -72,6 +86,18 @@ pub enum SyncAttrDetail {
     Smooth,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvAttr<'input> {
+    pub parsed: Option<Rc<parser::ast::EvAttr<'input>>>,
+    pub detail: EvAttrDetail,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EvAttrDetail {
+    None,
+    Custom,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct VarBind<'input> {
     pub parsed: Option<Rc<parser::ast::VarBind<'input>>>,
@@ -80,6 +106,38 @@ pub struct VarBind<'input> {
     pub vars: Vec<Rc<elements::var::Var>>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtyBind<'input> {
+    pub parsed: Option<Rc<parser::ast::NewtyBind<'input>>>,
+    pub ident: Rc<Ident<'input>>,
+    pub ty_expr: Rc<TyExpr<'input>>,
+    pub methods: Vec<Rc<Ident<'input>>>,
+    pub ty: Rc<elements::ty::Ty>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModBind<'input> {
+    pub parsed: Option<Rc<parser::ast::ModBind<'input>>>,
+    pub ident: Rc<Ident<'input>>,
+    pub top_stats: Vec<Rc<TopStat<'input>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBind<'input> {
+    pub parsed: Option<Rc<parser::ast::UseBind<'input>>>,
+    pub idents: Vec<Rc<Ident<'input>>>,
+    pub detail: Rc<UseBindDetail<'input>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UseBindDetail<'input> {
+    Single,
+    Alias {
+        ident: Rc<Ident<'input>>,
+    },
+    Glob,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct VarDecl<'input> {
     pub parsed: Option<Rc<parser::ast::VarDecl<'input>>>,
@@ -129,6 +187,24 @@ pub struct FnDecl<'input> {
     pub ty_expr: Rc<TyExpr<'input>>
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumBind<'input> {
+    pub parsed: Option<Rc<parser::ast::EnumBind<'input>>>,
+    pub ident: Rc<Ident<'input>>,
+    pub members: Vec<Rc<Ident<'input>>>,
+    pub ty: Rc<elements::ty::Ty>,
+    pub vars: Vec<Rc<elements::var::Var>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TyBind<'input> {
+    pub parsed: Option<Rc<parser::ast::TyBind<'input>>>,
+    pub ident: Rc<Ident<'input>>,
+    pub ty_params: Vec<Rc<Ident<'input>>>,
+    pub ty_expr: Rc<TyExpr<'input>>,
+    pub ty: Rc<elements::ty::Ty>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TyExpr<'input> {
     pub parsed: Option<Rc<parser::ast::TyExpr<'input>>>,
@@ -151,6 +227,8 @@ pub enum TyExprDetail<'input> {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TyOp {
     Access,
+    Apply,
+    Nullable,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -166,6 +244,9 @@ pub enum TyTermDetail<'input> {
     EvalTy {
         ident: Rc<Ident<'input>>,
     },
+    ApplyTy {
+        ty_exprs: Vec<Rc<TyExpr<'input>>>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -194,9 +275,13 @@ pub enum StatDetail<'input> {
     FnBind {
         fn_bind: Rc<FnBind<'input>>,
     },
+    TyBind {
+        ty_bind: Rc<TyBind<'input>>,
+    },
     Expr {
         expr: Rc<Expr<'input>>,
     },
+    Error,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -217,6 +302,13 @@ pub enum ExprDetail<'input> {
         op: Op,
         right: Rc<Expr<'input>>,
     },
+    Coalescing {
+        left: Rc<Expr<'input>>,
+        right: Rc<Expr<'input>>,
+        scrutinee: Rc<elements::var::Var>,
+        test: Rc<PatternTest>,
+        result: Rc<elements::var::Var>,
+    },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -267,6 +359,7 @@ pub enum TermDetail<'input> {
         ty_expr: Rc<TyExpr<'input>>,
     },
     ApplyFn {
+        ty_exprs: Vec<Rc<TyExpr<'input>>>,
         args: Vec<Rc<ArgExpr<'input>>>,
         as_fn: RefCell<Option<Rc<AsFn<'input>>>>,
     },
@@ -295,7 +388,9 @@ pub enum TermDetail<'input> {
     Literal {
         literal: Rc<elements::literal::Literal>,
     },
-    ThisLiteral,
+    ThisLiteral {
+        literal: Rc<elements::literal::Literal>,
+    },
     InterpolatedString {
         interpolated_string: Rc<InterpolatedString<'input>>,
     },
@@ -311,6 +406,14 @@ pub enum TermDetail<'input> {
         condition: Rc<Expr<'input>>,
         if_part: Rc<StatsBlock<'input>>,
         else_part: Option<Rc<StatsBlock<'input>>>,
+        result: Option<Rc<elements::var::Var>>,
+    },
+    Match {
+        expr: Rc<Expr<'input>>,
+        match_arms: Vec<Rc<MatchArm<'input>>>,
+        scrutinee: Rc<elements::var::Var>,
+        result: Option<Rc<elements::var::Var>>,
+        jump_table: Option<Rc<JumpTable>>,
     },
     While {
         condition: Rc<Expr<'input>>,
@@ -343,6 +446,67 @@ pub enum AsFn<'input> {
     Method(Rc<elements::method::Method>),
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchArm<'input> {
+    pub parsed: Option<Rc<parser::ast::MatchArm<'input>>>,
+    pub pattern: Rc<Pattern<'input>>,
+    pub expr: Rc<Expr<'input>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern<'input> {
+    pub parsed: Option<Rc<parser::ast::Pattern<'input>>>,
+    pub detail: Rc<PatternDetail<'input>>,
+    pub ty: Rc<elements::ty::Ty>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternDetail<'input> {
+    Wildcard,
+    Literal {
+        literal: Rc<elements::literal::Literal>,
+        test: Rc<PatternTest>,
+    },
+    Range {
+        left: Rc<elements::literal::Literal>,
+        right: Rc<elements::literal::Literal>,
+        lower: Rc<PatternTest>,
+        upper: Rc<PatternTest>,
+    },
+    Tuple {
+        patterns: Vec<Rc<Pattern<'input>>>,
+    },
+    EvalVar {
+        idents: Vec<Rc<Ident<'input>>>,
+        var: Rc<elements::var::Var>,
+        literal: Rc<elements::literal::Literal>,
+        test: Rc<PatternTest>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternTest {
+    pub index: usize,
+    pub method: Rc<elements::method::Method>,
+    pub operand: Rc<elements::literal::Literal>,
+    pub cond: Rc<elements::var::Var>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JumpTable {
+    pub entries: Vec<Option<usize>>,
+    pub fallback: Option<usize>,
+    pub lower: Rc<PatternTest>,
+    pub upper: Rc<PatternTest>,
+    pub sub: Rc<elements::method::Method>,
+    pub to_uint: Rc<elements::method::Method>,
+    pub mul: Rc<elements::method::Method>,
+    pub add: Rc<elements::method::Method>,
+    pub stride: Rc<elements::literal::Literal>,
+    pub offset: Rc<elements::var::Var>,
+    pub addr: Rc<elements::var::Var>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct IterExpr<'input> {
     pub parsed: Option<Rc<parser::ast::IterExpr<'input>>>,
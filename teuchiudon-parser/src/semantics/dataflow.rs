@@ -0,0 +1,337 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    mem,
+    rc::Rc,
+};
+use crate::context::Context;
+use crate::error::diagnostic::ErrorCode;
+use super::{
+    SemanticError,
+    ast,
+    elements::{
+        ev::Ev,
+        var::Var,
+    },
+};
+
+/// Events Udon may fire before `_start`, where the top-level statements run.
+const EARLY_EVS: [&str; 2] = ["_onEnable", "_onDeserialization"];
+
+/// Reports reads of top-level bindings from events that may run before the bindings are initialized in `_start`,
+/// unless the event assigns them on every path leading to the read.
+pub fn uninitialized_warnings<'input: 'context, 'context>(
+    context: &'context Context<'input>,
+    body: &ast::Body<'input>,
+) {
+    let mut inits = HashMap::new();
+    top_level_inits(&body.top_stats, &mut inits);
+    if inits.is_empty() {
+        return;
+    }
+    let mut reported = HashSet::new();
+    for (ev, fn_bind) in early_evs(&body.top_stats) {
+        let mut reads = Reads::new(&inits);
+        reads.stats_block(&fn_bind.stats_block);
+        for (id, slice) in reads.found {
+            if !reported.insert((slice.as_ptr(), slice.len())) {
+                continue;
+            }
+            let (name, init) = &inits[&id];
+            context.warning_store.push(Rc::new(
+                SemanticError::new(ErrorCode::PossiblyUninitialized, Some(slice), format!("Variable `{}` may be read before it is initialized", name))
+                .with_label(Some(*init), format!("Initialized in `_start`, which `{}` may precede", ev.name))
+            ));
+        }
+    }
+}
+
+fn top_level_inits<'input>(top_stats: &[Rc<ast::TopStat<'input>>], inits: &mut HashMap<usize, (String, &'input str)>) {
+    for top_stat in top_stats {
+        match top_stat.detail.as_ref() {
+            ast::TopStatDetail::VarBind { access_attr: _, sync_attr: _, var_bind } => {
+                let Some(parsed) = &var_bind.parsed
+                    else {
+                        continue;
+                    };
+                for var in &var_bind.vars {
+                    inits.insert(var.id, (var.name.clone(), parsed.slice));
+                }
+            },
+            ast::TopStatDetail::ModBind { mod_bind } =>
+                top_level_inits(&mod_bind.top_stats, inits),
+            _ => (),
+        }
+    }
+}
+
+fn early_evs<'input>(top_stats: &[Rc<ast::TopStat<'input>>]) -> Vec<(Rc<Ev>, Rc<ast::FnBind<'input>>)> {
+    top_stats.iter()
+    .filter_map(|x| match x.detail.as_ref() {
+        ast::TopStatDetail::FnBind { access_attr: _, ev_attr: _, fn_bind, ev: Some((ev, _)) } if EARLY_EVS.contains(&ev.real_name.as_str()) =>
+            Some((ev.clone(), fn_bind.clone())),
+        _ => None,
+    })
+    .collect()
+}
+
+/// Bindings assigned on every path reaching a point, or `None` when the point is unreachable.
+type Assigned = Option<HashSet<usize>>;
+
+fn merge(left: Assigned, right: Assigned) -> Assigned {
+    match (left, right) {
+        (None, x) | (x, None) =>
+            x,
+        (Some(left), Some(right)) =>
+            Some(left.intersection(&right).copied().collect()),
+    }
+}
+
+/// Collects the reads of top-level bindings reachable from a block that may happen before the bindings are assigned,
+/// following calls into functions.
+struct Reads<'input, 'inits> {
+    inits: &'inits HashMap<usize, (String, &'input str)>,
+    calling_fns: HashSet<usize>,
+    assigned: Assigned,
+    returned: Vec<Assigned>,
+    found: Vec<(usize, &'input str)>,
+}
+
+impl<'input, 'inits> Reads<'input, 'inits> {
+    fn new(inits: &'inits HashMap<usize, (String, &'input str)>) -> Self {
+        Self {
+            inits,
+            calling_fns: HashSet::new(),
+            assigned: Some(HashSet::new()),
+            returned: Vec::new(),
+            found: Vec::new(),
+        }
+    }
+
+    fn stats_block(&mut self, node: &ast::StatsBlock<'input>) {
+        for stat in &node.stats {
+            self.stat(stat);
+        }
+        self.expr(&node.ret);
+    }
+
+    fn stat(&mut self, node: &ast::Stat<'input>) {
+        match node.detail.as_ref() {
+            ast::StatDetail::Return { expr } => {
+                self.expr(expr);
+                let assigned = self.assigned.take();
+                if let Some(returned) = self.returned.last_mut() {
+                    *returned = merge(returned.take(), assigned);
+                }
+            },
+            ast::StatDetail::Expr { expr } =>
+                self.expr(expr),
+            ast::StatDetail::VarBind { var_bind } =>
+                self.expr(&var_bind.expr),
+            ast::StatDetail::Continue |
+            ast::StatDetail::Break => {
+                self.assigned = None;
+            },
+            ast::StatDetail::FnBind { fn_bind: _ } |
+            ast::StatDetail::TyBind { ty_bind: _ } |
+            ast::StatDetail::Error => (),
+        }
+    }
+
+    fn expr(&mut self, node: &ast::Expr<'input>) {
+        match node.detail.as_ref() {
+            ast::ExprDetail::Term { term } =>
+                self.term(term),
+            ast::ExprDetail::InfixOp { left, op: ast::Op::Assign, right } => {
+                self.expr(right);
+                if let Some(var) = eval_var(left) {
+                    self.assign(&var);
+                }
+            },
+            ast::ExprDetail::InfixOp { left, op: _, right } => {
+                self.expr(left);
+                self.expr(right);
+            },
+            ast::ExprDetail::Coalescing { left, right, scrutinee: _, test: _, result: _ } => {
+                self.expr(left);
+                let start = self.assigned.clone();
+                self.expr(right);
+                self.assigned = merge(start, self.assigned.take());
+            },
+        }
+    }
+
+    fn term(&mut self, node: &ast::Term<'input>) {
+        match node.detail.as_ref() {
+            ast::TermDetail::ApplyFn { ty_exprs: _, args, as_fn } => {
+                for arg in args {
+                    self.expr(&arg.expr);
+                }
+                if let Some(ast::AsFn::Fn(eval_fn)) = as_fn.borrow().as_deref() {
+                    let id = eval_fn.fn_stats.id;
+                    if self.calling_fns.insert(id) {
+                        self.returned.push(None);
+                        self.stats_block(&eval_fn.fn_stats.stats);
+                        let returned = self.returned.pop().flatten();
+                        self.assigned = merge(self.assigned.take(), returned);
+                        self.calling_fns.remove(&id);
+                    }
+                }
+            },
+            ast::TermDetail::Closure { var_decl: _, expr } => {
+                let start = self.assigned.clone();
+                self.expr(expr);
+                self.assigned = start;
+            },
+            ast::TermDetail::ApplySpreadFn { arg: expr } |
+            ast::TermDetail::ApplyKey { key: expr } |
+            ast::TermDetail::Paren { expr } =>
+                self.expr(expr),
+            ast::TermDetail::PrefixOp { op: _, term } =>
+                self.term(term),
+            ast::TermDetail::Block { stats } =>
+                self.stats_block(stats),
+            ast::TermDetail::Loop { stats } => {
+                let start = self.assigned.clone();
+                self.stats_block(stats);
+                self.assigned = start;
+            },
+            ast::TermDetail::Tuple { exprs } => {
+                for expr in exprs {
+                    self.expr(expr);
+                }
+            },
+            ast::TermDetail::ArrayCtor { iter_expr } =>
+                self.iter_expr(iter_expr),
+            ast::TermDetail::InterpolatedString { interpolated_string } => {
+                for expr in &interpolated_string.exprs {
+                    self.expr(expr);
+                }
+            },
+            ast::TermDetail::EvalVar { ident: _, var } => {
+                let Some(var) = var.borrow().clone()
+                    else {
+                        return;
+                    };
+                let Some(assigned) = &self.assigned
+                    else {
+                        return;
+                    };
+                if let (true, false, Some(parsed)) = (self.inits.contains_key(&var.id), assigned.contains(&var.id), &node.parsed) {
+                    self.found.push((var.id, parsed.slice));
+                }
+            },
+            ast::TermDetail::LetInBind { var_bind, expr } => {
+                self.expr(&var_bind.expr);
+                self.expr(expr);
+            },
+            ast::TermDetail::If { condition, if_part, else_part, result: _ } => {
+                self.expr(condition);
+                let start = self.assigned.clone();
+                self.stats_block(if_part);
+                let if_assigned = mem::replace(&mut self.assigned, start);
+                if let Some(else_part) = else_part {
+                    self.stats_block(else_part);
+                }
+                self.assigned = merge(if_assigned, self.assigned.take());
+            },
+            ast::TermDetail::Match { expr, match_arms, scrutinee: _, result: _, jump_table: _ } => {
+                self.expr(expr);
+                let start = self.assigned.clone();
+                let mut arms_assigned = if match_arms.is_empty() { start.clone() } else { None };
+                for match_arm in match_arms {
+                    self.assigned = start.clone();
+                    self.expr(&match_arm.expr);
+                    arms_assigned = merge(arms_assigned, self.assigned.take());
+                }
+                self.assigned = arms_assigned;
+            },
+            ast::TermDetail::While { condition, stats } => {
+                self.expr(condition);
+                let start = self.assigned.clone();
+                self.stats_block(stats);
+                self.assigned = start;
+            },
+            ast::TermDetail::For { for_binds, stats } => {
+                let start = self.assigned.clone();
+                for for_bind in for_binds {
+                    self.for_bind(for_bind);
+                }
+                self.stats_block(stats);
+                self.assigned = start;
+            },
+            ast::TermDetail::None |
+            ast::TermDetail::TyExpr { ty_expr: _ } |
+            ast::TermDetail::Literal { literal: _ } |
+            ast::TermDetail::ThisLiteral { literal: _ } => (),
+        }
+    }
+
+    fn iter_expr(&mut self, node: &ast::IterExpr<'input>) {
+        match node.detail.as_ref() {
+            ast::IterExprDetail::Empty => (),
+            ast::IterExprDetail::Range { left, right } => {
+                self.expr(left);
+                self.expr(right);
+            },
+            ast::IterExprDetail::SteppedRange { left, right, step } => {
+                self.expr(left);
+                self.expr(right);
+                self.expr(step);
+            },
+            ast::IterExprDetail::Spread { expr } =>
+                self.expr(expr),
+            ast::IterExprDetail::Elements { exprs } => {
+                for expr in exprs {
+                    self.expr(expr);
+                }
+            },
+        }
+    }
+
+    /// Evaluates the iteration of a `for` binding; an assigned loop variable counts as assigned inside the body only.
+    fn for_bind(&mut self, node: &ast::ForBind<'input>) {
+        let (left, for_iter_expr) = match node.detail.as_ref() {
+            ast::ForBindDetail::Let { var_decl: _, for_iter_expr } =>
+                (None, for_iter_expr),
+            ast::ForBindDetail::Assign { left, for_iter_expr } =>
+                (eval_var(left), for_iter_expr),
+        };
+        match for_iter_expr.detail.as_ref() {
+            ast::ForIterExprDetail::Range { left, right } => {
+                self.expr(left);
+                self.expr(right);
+            },
+            ast::ForIterExprDetail::SteppedRange { left, right, step } => {
+                self.expr(left);
+                self.expr(right);
+                self.expr(step);
+            },
+            ast::ForIterExprDetail::Spread { expr } =>
+                self.expr(expr),
+        }
+        if let Some(var) = left {
+            self.assign(&var);
+        }
+    }
+
+    fn assign(&mut self, var: &Var) {
+        if let Some(assigned) = &mut self.assigned {
+            assigned.insert(var.id);
+        }
+    }
+}
+
+fn eval_var(node: &ast::Expr) -> Option<Rc<Var>> {
+    let ast::ExprDetail::Term { term } = node.detail.as_ref()
+        else {
+            return None;
+        };
+    let ast::TermDetail::EvalVar { ident: _, var } = term.detail.as_ref()
+        else {
+            return None;
+        };
+    var.borrow().clone()
+}
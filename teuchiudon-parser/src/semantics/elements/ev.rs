@@ -105,6 +105,35 @@ impl Ev {
     ) -> Result<Rc<Self>, ElementError> {
         EvKey::new(name).get_value(context)
     }
+
+    /// Finds the event whose name is the closest to `name`, ignoring case, within a third of its length in edit distance.
+    pub fn closest<'input>(
+        context: &Context<'input>,
+        name: &str,
+    ) -> Option<Rc<Self>> {
+        let name = name.to_lowercase();
+        let max_distance = (name.chars().count() / 3).max(1);
+        context.ev_store.values()
+        .map(|x| (edit_distance(&name, &x.name.to_lowercase()), x))
+        .filter(|(d, _)| *d <= max_distance)
+        .min_by(|(d1, x1), (d2, x2)| d1.cmp(d2).then_with(|| x1.name.cmp(&x2.name)))
+        .map(|(_, x)| x)
+    }
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut row = (0..=right.len()).collect::<Vec<_>>();
+    for (i, l) in left.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, r) in right.iter().enumerate() {
+            let substituted = diagonal + usize::from(l != *r);
+            diagonal = row[j + 1];
+            row[j + 1] = substituted.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[right.len()]
 }
 
 impl EvKey {
@@ -1,10 +1,12 @@
 use std::rc::Rc;
 use crate::impl_key_value_elements;
 use crate::context::Context;
+use crate::error::diagnostic::ErrorCode;
 use super::{
     ElementError,
     base_ty::BaseTy,
     element::{
+        KeyElement,
         SemanticElement,
         ValueElement,
     },
@@ -71,18 +73,36 @@ impl Literal {
         Ok(value)
     }
 
+    pub fn new_or_get<'input>(
+        context: &Context<'input>,
+        text: String,
+        ty: Rc<Ty>
+    ) -> Result<Rc<Self>, ElementError> {
+        match LiteralKey::new(text.clone(), ty.to_key()).get_value(context) {
+            Ok(x) => Ok(x),
+            Err(_) => Self::new(context, text, ty),
+        }
+    }
+
     pub fn new_unit<'input>(
         context: &Context<'input>
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "unit")?.new_or_get_applied_zero(context)?;
-        Self::new(context, "()".to_owned(), ty)
+        Self::new_or_get(context, "()".to_owned(), ty)
     }
 
     pub fn new_null<'input>(
         context: &Context<'input>
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "nulltype")?.new_or_get_applied_zero(context)?;
-        Self::new(context, "null".to_owned(), ty)
+        Self::new_or_get(context, "null".to_owned(), ty)
+    }
+
+    pub fn new_this<'input>(
+        context: &Context<'input>
+    ) -> Result<Rc<Self>, ElementError> {
+        let ty = BaseTy::get_from_name(context, "udon")?.new_or_get_applied_zero(context)?;
+        Self::new_or_get(context, "this".to_owned(), ty)
     }
 
     pub fn new_bool<'input>(
@@ -90,51 +110,62 @@ impl Literal {
         text: String
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "bool")?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, text, ty)
     }
 
     pub fn new_pure_integer<'input>(
         context: &Context<'input>,
         text: String
     ) -> Result<Rc<Self>, ElementError> {
-        let ty = BaseTy::get_from_name(context, "int")?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_integer(context, text)
     }
 
     pub fn new_dec_integer<'input>(
         context: &Context<'input>,
         text: String
     ) -> Result<Rc<Self>, ElementError> {
-        let (text, ty_name) = Self::trim_integer_text(text);
-        let ty = BaseTy::get_from_name(context, ty_name)?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_integer(context, text)
     }
 
     pub fn new_hex_integer<'input>(
         context: &Context<'input>,
         text: String
     ) -> Result<Rc<Self>, ElementError> {
-        let (text, ty_name) = Self::trim_integer_text(text);
-        let ty = BaseTy::get_from_name(context, ty_name)?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_integer(context, text)
     }
 
     pub fn new_bin_integer<'input>(
         context: &Context<'input>,
         text: String
     ) -> Result<Rc<Self>, ElementError> {
-        let (text, ty_name) = Self::trim_integer_text(text);
+        Self::new_integer(context, text)
+    }
+
+    fn new_integer<'input>(
+        context: &Context<'input>,
+        text: String
+    ) -> Result<Rc<Self>, ElementError> {
+        let (trimmed, ty_names) = Self::trim_integer_text(text.clone());
+        let value = Self::parse_integer(&trimmed);
+        let ty_name =
+            ty_names.iter()
+            .find(|x| value.is_some_and(|v| Self::integer_range(x).is_some_and(|(min, max)| min <= v && v <= max)))
+            .ok_or_else(|| Self::out_of_range(&text, ty_names[ty_names.len() - 1]))?;
         let ty = BaseTy::get_from_name(context, ty_name)?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, trimmed, ty)
     }
 
     pub fn new_real_number<'input>(
         context: &Context<'input>,
         text: String
     ) -> Result<Rc<Self>, ElementError> {
-        let (text, ty_name) = Self::trim_real_number_text(text);
+        let (trimmed, ty_names) = Self::trim_real_number_text(text.clone());
+        let ty_name =
+            ty_names.iter()
+            .find(|x| Self::real_in_range(&trimmed, x))
+            .ok_or_else(|| Self::out_of_range(&text, ty_names[ty_names.len() - 1]))?;
         let ty = BaseTy::get_from_name(context, ty_name)?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, trimmed, ty)
     }
 
     pub fn new_character<'input>(
@@ -142,7 +173,7 @@ impl Literal {
         text: String
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "char")?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, text, ty)
     }
 
     pub fn new_regular_string<'input>(
@@ -150,7 +181,7 @@ impl Literal {
         text: String
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "string")?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, text, ty)
     }
 
     pub fn new_verbatium_string<'input>(
@@ -158,38 +189,102 @@ impl Literal {
         text: String
     ) -> Result<Rc<Self>, ElementError> {
         let ty = BaseTy::get_from_name(context, "string")?.new_or_get_applied_zero(context)?;
-        Self::new(context, text, ty)
+        Self::new_or_get(context, text, ty)
+    }
+
+    pub fn is_this(&self) -> bool {
+        self.text == "this" && self.ty.base.name == "udon"
     }
 
-    fn trim_integer_text(text: String) -> (String, &'static str) {
+    fn trim_integer_text(text: String) -> (String, &'static [&'static str]) {
         let text = Self::formatted_number(text);
         match &text.chars().collect::<Vec<_>>()[..] {
             [.., x, y] if *x == 'L' && *y == 'U' || *x == 'U' && *y == 'L' =>
-                (text[0..text.len() - 2].to_owned(), "ulong"),
+                (text[0..text.len() - 2].to_owned(), &["ulong"]),
             [.., x] if *x == 'L' =>
-                (text[0..text.len() - 1].to_owned(), "long"),
+                (text[0..text.len() - 1].to_owned(), &["long", "ulong"]),
             [.., x] if *x == 'U' =>
-                (text[0..text.len() - 1].to_owned(), "uint"),
+                (text[0..text.len() - 1].to_owned(), &["uint", "ulong"]),
             _ =>
-                (text, "int"),
+                (text, &["int", "uint", "long", "ulong"]),
         }
     }
 
-    fn trim_real_number_text(text: String) -> (String, &'static str) {
+    fn trim_real_number_text(text: String) -> (String, &'static [&'static str]) {
         let text = Self::formatted_number(text);
         match &text.chars().collect::<Vec<_>>()[..] {
             [.., x] if *x == 'F' =>
-                (text[0..text.len() - 1].to_owned(), "float"),
+                (text[0..text.len() - 1].to_owned(), &["float"]),
             [.., x] if *x == 'D' =>
-                (text[0..text.len() - 1].to_owned(), "double"),
+                (text[0..text.len() - 1].to_owned(), &["double"]),
             [.., x] if *x == 'M' =>
-                (text[0..text.len() - 1].to_owned(), "decimal"),
+                (text[0..text.len() - 1].to_owned(), &["decimal"]),
             _ =>
-                (text, "float"),
+                (text, &["float", "double"]),
         }
     }
 
     fn formatted_number(text: String) -> String {
         text.to_uppercase().replace("_", "")
     }
+
+    pub fn parse_integer(text: &str) -> Option<i128> {
+        if let Some(x) = text.strip_prefix("0X") {
+            i128::from_str_radix(x, 16).ok()
+        }
+        else if let Some(x) = text.strip_prefix("0B") {
+            i128::from_str_radix(x, 2).ok()
+        }
+        else {
+            text.parse().ok()
+        }
+    }
+
+    pub fn integer_range(ty_name: &str) -> Option<(i128, i128)> {
+        match ty_name {
+            "sbyte" => Some((i8::MIN.into(), i8::MAX.into())),
+            "byte" => Some((u8::MIN.into(), u8::MAX.into())),
+            "short" => Some((i16::MIN.into(), i16::MAX.into())),
+            "ushort" => Some((u16::MIN.into(), u16::MAX.into())),
+            "int" => Some((i32::MIN.into(), i32::MAX.into())),
+            "uint" => Some((u32::MIN.into(), u32::MAX.into())),
+            "long" => Some((i64::MIN.into(), i64::MAX.into())),
+            "ulong" => Some((u64::MIN.into(), u64::MAX.into())),
+            _ => None,
+        }
+    }
+
+    fn real_in_range(text: &str, ty_name: &str) -> bool {
+        let Ok(value) = text.parse::<f64>()
+            else {
+                return false;
+            };
+        match ty_name {
+            "float" => value.abs() <= f32::MAX.into(),
+            "double" => value.is_finite(),
+            "decimal" => value.abs() < 79228162514264337593543950336.0,
+            _ => false,
+        }
+    }
+
+    pub fn out_of_range(text: &str, ty_name: &str) -> ElementError {
+        let range = match Self::integer_range(ty_name) {
+            Some((min, max)) => format!("{} to {}", min, max),
+            None => match ty_name {
+                "float" => format!("{:E} to {:E}", f32::MIN, f32::MAX),
+                "double" => format!("{:E} to {:E}", f64::MIN, f64::MAX),
+                _ => "-79228162514264337593543950335 to 79228162514264337593543950335".to_owned(),
+            },
+        };
+        ElementError::new(ErrorCode::OutOfRange, format!("Literal `{}` is out of range for type `{}`, allowed range is {}", text, ty_name, range))
+    }
+}
+
+impl LiteralKey {
+    pub fn new(text: String, ty: TyLogicalKey) -> Self {
+        Self {
+            text,
+            ty,
+        }
+    }
 }
@@ -101,6 +101,20 @@ impl Method {
         Ok(value)
     }
 
+    pub fn has_ret(&self) -> bool {
+        self.param_in_outs.last() == Some(&MethodParamInOut::Out) &&
+        self.param_real_names.last().is_some_and(|x| x == "__returnValue")
+    }
+
+    pub fn to_result_order<T: Clone>(&self, outs: &[T]) -> Vec<T> {
+        match outs.split_last() {
+            Some((ret, params)) if self.has_ret() =>
+                [ret.clone()].into_iter().chain(params.iter().cloned()).collect(),
+            _ =>
+                outs.to_vec(),
+        }
+    }
+
     fn iter_in_or_in_out<'a, T: Clone + 'a>(
         iter: impl Iterator<Item = &'a T> + 'a,
         ios: impl Iterator<Item = &'a MethodParamInOut> + 'a
@@ -12,26 +12,31 @@ pub mod qual;
 pub mod scope;
 pub mod top_stat;
 pub mod ty;
+pub mod ty_alias;
 pub mod ty_op;
 pub mod valued_var;
 pub mod var;
 
+use crate::error::diagnostic::ErrorCode;
 use super::SemanticError;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ElementError {
+    pub code: ErrorCode,
     pub message: String,
 }
 
 impl ElementError {
-    pub fn new(message: String) -> Self {
+    pub fn new(code: ErrorCode, message: String) -> Self {
         Self {
+            code,
             message
         }
     }
 
     pub fn convert(self, slice: Option<&str>) -> Vec<SemanticError> {
         vec![SemanticError::new(
+            self.code,
             slice,
             self.message,
         )]
@@ -119,6 +119,15 @@ impl Qual {
         Self::new_or_get_one(context, cloned)
     }
 
+    pub fn new_alias<'input>(
+        context: &Context<'input>,
+        key: QualKey,
+        qual: &Rc<Self>
+    ) -> Result<Rc<Self>, ElementError> {
+        context.qual_store.add(key, qual.clone())?;
+        Ok(qual.clone())
+    }
+
     pub fn get<'input>(
         context: &Context<'input>,
         scopes: Vec<Scope>
@@ -27,6 +27,7 @@ pub struct Ty {
     pub logical_name: String,
     pub instance: Option<TyInstance>,
     pub parents: Vec<TyLogicalKey>,
+    pub alias: Option<TyKey>,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -56,6 +57,16 @@ pub enum TyInstance {
     Tuple { elem_name: Option<String>, instances: Vec<TyInstance> },
 }
 
+impl TyInstance {
+    pub fn elem_count(&self) -> usize {
+        match self {
+            Self::Unit => 0,
+            Self::Single { elem_name: _, ty_name: _ } => 1,
+            Self::Tuple { elem_name: _, instances } => instances.iter().map(|x| x.elem_count()).sum(),
+        }
+    }
+}
+
 impl PartialEq for Ty {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -72,7 +83,10 @@ impl Hash for Ty {
 
 impl SemanticElement for Ty {
     fn description(&self) -> String {
-        <Ty as ValueElement<TyKey>>::to_key(self).description()
+        match &self.alias {
+            Some(x) => x.description(),
+            None => <Ty as ValueElement<TyKey>>::to_key(self).description(),
+        }
     }
 
     fn logical_name(&self) -> String {
@@ -194,6 +208,7 @@ impl Ty {
             logical_name,
             instance,
             parents,
+            alias: None,
         });
         let key = value.to_key();
         let logical_key: TyLogicalKey = value.to_key();
@@ -230,6 +245,7 @@ impl Ty {
             logical_name: Self::logical_name(&base, &args),
             instance,
             parents: Vec::new(),
+            alias: None,
         });
 
         let key: TyKey = value.to_key();
@@ -267,6 +283,19 @@ impl Ty {
         }
     }
 
+    pub fn new_alias<'input>(
+        context: &Context<'input>,
+        key: TyKey,
+        ty: &Rc<Self>
+    ) -> Result<Rc<Self>, ElementError> {
+        let value = Rc::new(Self {
+            alias: Some(key.clone()),
+            ..ty.as_ref().clone()
+        });
+        context.ty_store.add(key, value.clone())?;
+        Ok(value)
+    }
+
     pub fn get<'input>(
         context: &Context<'input>,
         qual: QualKey,
@@ -324,6 +353,13 @@ impl Ty {
                 },
             "any" =>
                 Ok(Some(TyInstance::Single { elem_name: None, ty_name: "SystemObject".to_owned() })),
+            "nullable" =>
+                match args.as_slice() {
+                    [TyArg::Ty(x)] =>
+                        Ok(x.get_value(context)?.instance.clone()),
+                    _ =>
+                        panic!("Illegal state"),
+                },
             _ =>
                 Ok(None),
         }
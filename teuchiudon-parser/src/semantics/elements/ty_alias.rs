@@ -0,0 +1,83 @@
+use std::rc::Rc;
+use crate::impl_key_value_elements;
+use crate::context::Context;
+use crate::parser;
+use super::{
+    ElementError,
+    element::{
+        SemanticElement,
+        ValueElement,
+    },
+    qual::QualKey,
+    ty::Ty,
+};
+
+#[derive(Clone, Debug)]
+pub struct TyAlias<'input> {
+    pub id: usize,
+    pub ty: Rc<Ty>,
+    pub ty_params: Vec<String>,
+    pub ty_expr: Rc<parser::ast::TyExpr<'input>>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TyAliasKey {
+    pub qual: QualKey,
+    pub name: String,
+}
+
+impl_key_value_elements!(
+    TyAliasKey,
+    TyAlias<'input>,
+    TyAliasKey {
+        qual: self.ty.base.qual.to_key(),
+        name: self.ty.base.name.clone()
+    },
+    ty_alias_store
+);
+
+impl SemanticElement for TyAliasKey {
+    fn description(&self) -> String {
+        format!(
+            "{}{}",
+            self.qual.qualify_description("::"),
+            self.name.description()
+        )
+    }
+
+    fn logical_name(&self) -> String {
+        format!(
+            "alias[{}{}]",
+            self.qual.qualify_logical_name(">"),
+            self.name.logical_name()
+        )
+    }
+}
+
+impl<'input> TyAlias<'input> {
+    pub fn new(
+        context: &Context<'input>,
+        ty: Rc<Ty>,
+        ty_params: Vec<String>,
+        ty_expr: Rc<parser::ast::TyExpr<'input>>,
+    ) -> Result<Rc<Self>, ElementError> {
+        let value = Rc::new(Self {
+            id: context.ty_alias_store.next_id(),
+            ty,
+            ty_params,
+            ty_expr,
+        });
+        let key = value.to_key();
+        context.ty_alias_store.add(key, value.clone())?;
+        Ok(value)
+    }
+}
+
+impl TyAliasKey {
+    pub fn new(qual: QualKey, name: String) -> Self {
+        Self {
+            qual,
+            name,
+        }
+    }
+}
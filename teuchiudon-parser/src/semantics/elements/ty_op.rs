@@ -1,5 +1,6 @@
 use std::rc::Rc;
 use crate::context::Context;
+use crate::error::diagnostic::ErrorCode;
 use super::{
     ElementError,
     base_ty::BaseTyKey,
@@ -88,6 +89,15 @@ impl Ty {
         Self::get_array_from_key(context, Ty::get_from_name(context, name)?.to_key())
     }
 
+    pub fn new_or_get_nullable_from_key<'input>(
+        context: &Context<'input>,
+        key: TyLogicalKey
+    ) -> Result<Rc<Self>, ElementError> {
+        let base = BaseTyKey::from_name("nullable").get_value(context)?;
+        let arg = key.get_value(context)?;
+        Self::new_or_get(context, base, vec![TyArg::Ty(arg.to_key())])
+    }
+
     pub fn new_or_get_function_from_key<'input>(
         context: &Context<'input>,
         key: FnKey,
@@ -140,8 +150,7 @@ impl Ty {
         context: &Context<'input>,
         key: TyKey
     ) -> bool {
-        let ty = key.get_value(context);
-        ty.is_ok() && self.logical_name == ty.unwrap().logical_name
+        key.get_value(context).is_ok_and(|x| x == *self)
     }
     
     pub fn logical_eq_with_name<'input>(
@@ -223,6 +232,35 @@ impl Ty {
         }
     }
 
+    pub fn arg_as_nullable(self: &Rc<Self>) -> TyLogicalKey {
+        if !self.base_eq_with_name("nullable") {
+            panic!("Illegal state")
+        }
+        if self.args.len() == 1 {
+            match &self.args[0] {
+                TyArg::Ty(x) =>
+                    x.clone(),
+                _ =>
+                    panic!("Illegal state"),
+            }
+        }
+        else {
+            panic!("Illegal state")
+        }
+    }
+
+    pub fn non_null<'input>(
+        self: &Rc<Self>,
+        context: &Context<'input>
+    ) -> Result<Rc<Self>, ElementError> {
+        if self.base_eq_with_name("nullable") {
+            self.arg_as_nullable().get_value(context)
+        }
+        else {
+            Ok(self.clone())
+        }
+    }
+
     pub fn arg_as_function(self: &Rc<Self>) -> FnKey {
         if !self.base_eq_with_name("function") {
             panic!("Illegal state")
@@ -306,6 +344,8 @@ impl Ty {
         self.assignable_from_method(context, ty) ||
         self.assignable_from_getter(context, ty) ||
         self.assignable_from_setter(context, ty) ||
+        self.assignable_from_nullable(context, ty) ||
+        self.assignable_from_null(context, ty) ||
         self.assignable_from_dotnet_ty(context, ty)
     }
 
@@ -424,13 +464,42 @@ impl Ty {
         false
     }
 
+    fn assignable_from_nullable<'input>(
+        self: &Rc<Self>,
+        context: &Context<'input>,
+        ty: &Rc<Self>
+    ) -> bool {
+        if self.base_eq_with_name("nullable") {
+            ty.base_eq_with_name("nulltype") || {
+                let self_ty = self.non_null(context);
+                let ty_ty = ty.non_null(context);
+                self_ty.is_ok() && ty_ty.is_ok() &&
+                self_ty.unwrap().assignable_from(context, &ty_ty.unwrap())
+            }
+        }
+        else {
+            !context.options.null_safety && ty.base_eq_with_name("nullable") && {
+                let ty_ty = ty.non_null(context);
+                ty_ty.is_ok() && self.assignable_from(context, &ty_ty.unwrap())
+            }
+        }
+    }
+
+    fn assignable_from_null<'input>(
+        self: &Rc<Self>,
+        context: &Context<'input>,
+        ty: &Rc<Self>
+    ) -> bool {
+        !context.options.null_safety && ty.base_eq_with_name("nulltype") && self.is_reference_ty(context)
+    }
+
     fn assignable_from_dotnet_ty<'input>(
         self: &Rc<Self>,
-        _context: &Context<'input>,
+        context: &Context<'input>,
         ty: &Rc<Self>
     ) -> bool {
         self.is_dotnet_ty() && ty.is_dotnet_ty() &&
-        ty.parents.contains(&self.to_key())
+        (self == ty || ty.parents.iter().any(|x| x.get_value(context).is_ok_and(|x| x == *self)))
     }
 
     pub fn is_dotnet_ty(self: &Rc<Self>) -> bool {
@@ -448,11 +517,48 @@ impl Ty {
             "method",
             "getter",
             "setter",
+            "nullable",
         ]
         .iter()
         .all(|x| !self.base_eq_with_name(x))
     }
 
+    pub fn is_wrapper_ty(self: &Rc<Self>) -> bool {
+        self.logical_name.starts_with("enum[") || self.logical_name.starts_with("newty[")
+    }
+
+    pub fn is_reference_ty<'input>(
+        self: &Rc<Self>,
+        context: &Context<'input>
+    ) -> bool {
+        self.is_dotnet_ty() &&
+        !self.base_eq_with_name("nulltype") &&
+        !self.parents.iter().any(|x| x.logical_name == "SystemValueType") &&
+        !vec![
+            "bool",
+            "byte",
+            "sbyte",
+            "short",
+            "ushort",
+            "int",
+            "uint",
+            "long",
+            "ulong",
+            "float",
+            "double",
+            "decimal",
+            "char",
+            "vec2",
+            "vec3",
+            "vec4",
+            "quat",
+            "color",
+            "color32",
+        ]
+        .iter()
+        .any(|x| self.logical_eq_with_name(context, x))
+    }
+
     pub fn is_syncable<'input>(
         self: &Rc<Self>,
         context: &Context<'input>
@@ -570,6 +676,31 @@ impl Ty {
         .any(|x| self.logical_eq_with_name(context, x))
     }
 
+    pub fn implicitly_convertible_from<'input>(
+        self: &Rc<Self>,
+        context: &Context<'input>,
+        ty: &Rc<Self>
+    ) -> bool {
+        let widenings: [(&str, &[&str]); 10] = [
+            ("sbyte", &["short", "int", "long", "float", "double", "decimal"]),
+            ("byte", &["short", "ushort", "int", "uint", "long", "ulong", "float", "double", "decimal"]),
+            ("short", &["int", "long", "float", "double", "decimal"]),
+            ("ushort", &["int", "uint", "long", "ulong", "float", "double", "decimal"]),
+            ("int", &["long", "float", "double", "decimal"]),
+            ("uint", &["long", "ulong", "float", "double", "decimal"]),
+            ("long", &["float", "double", "decimal"]),
+            ("ulong", &["float", "double", "decimal"]),
+            ("char", &["ushort", "int", "uint", "long", "ulong", "float", "double", "decimal"]),
+            ("float", &["double"]),
+        ];
+        widenings
+        .iter()
+        .any(|(from, tos)|
+            ty.logical_eq_with_name(context, from) &&
+            tos.iter().any(|x| self.logical_eq_with_name(context, x))
+        )
+    }
+
     pub fn contains_unknown<'input>(
         self: &Rc<Self>,
         context: &Context<'input>
@@ -586,6 +717,9 @@ impl Ty {
         if self.base_eq_with_name("unknown") {
             Ok(ty.clone())
         }
+        else if self.base_eq_with_name("nullable") && !ty.base_eq_with_name("nullable") && self.assignable_from(context, ty) {
+            Ok(self.clone())
+        }
         else if self.assignable_from(context, ty) {
             let args = self.args.iter().zip(ty.args.iter())
             .map(|(s, t)| match (s, t) {
@@ -599,18 +733,70 @@ impl Ty {
             })
             .collect::<Result<_, _>>()?;
 
-            if self.is_dotnet_ty() {
-                self.base.get_applied(context, args)
-            }
-            else {
-                self.base.new_or_get_applied(context, args)
-            }
+            let ty =
+                if self.is_dotnet_ty() {
+                    self.base.get_applied(context, args)?
+                }
+                else {
+                    self.base.new_or_get_applied(context, args)?
+                };
+            Ok(if ty == *self { self.clone() } else { ty })
         }
         else {
-            Err(ElementError::new(format!("Cannot be inferred type `{}` from `{}`", self.description(), ty.description())))
+            Err(ElementError::new(ErrorCode::TypeMismatch, format!("Cannot be inferred type `{}` from `{}`", self.description(), ty.description())))
         }
     }
 
+    pub fn common_supertype<'input>(
+        context: &Context<'input>,
+        tys: &[Rc<Self>]
+    ) -> Result<Rc<Self>, ElementError> {
+        let tys =
+            tys.iter()
+            .filter(|x| !x.base_eq_with_name("never"))
+            .cloned()
+            .collect::<Vec<_>>();
+        if tys.is_empty() {
+            return Self::get_from_name(context, "never");
+        }
+
+        let candidates =
+            tys.iter()
+            .flat_map(|x|
+                std::iter::once(Ok(x.clone()))
+                .chain(
+                    x.parents.iter()
+                    .filter(|x| x.logical_name != "SystemObject")
+                    .map(|x| x.get_value(context))
+                )
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        let supertypes =
+            candidates.into_iter()
+            .filter(|x| tys.iter().all(|t| x.assignable_from(context, t)))
+            .collect::<Vec<_>>();
+        if let Some(ty) = supertypes.iter().find(|x| supertypes.iter().all(|s| s.assignable_from(context, x))) {
+            return Ok(ty.clone());
+        }
+
+        let non_nulls =
+            tys.iter()
+            .filter(|x| !x.base_eq_with_name("nulltype"))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !non_nulls.is_empty() && non_nulls.len() < tys.len() {
+            let ty = Self::common_supertype(context, &non_nulls)?;
+            if ty.is_reference_ty(context) {
+                return Self::new_or_get_nullable_from_key(context, ty.to_key());
+            }
+        }
+
+        Err(ElementError::new(ErrorCode::TypeMismatch, format!(
+            "No common type found for `{}`",
+            tys.iter().map(|x| x.description()).collect::<Vec<_>>().join("`, `")
+        )))
+    }
+
     pub fn most_compatible_method<'input>(
         self: &Rc<Self>,
         context: &Context<'input>,
@@ -626,7 +812,7 @@ impl Ty {
             .filter(|x| x.in_tys.len() == in_tys.len())
             .collect::<Vec<_>>();
         if methods.len() == 0 {
-            return Err(ElementError::new("No compatible methods found".to_owned()));
+            return Err(ElementError::new(ErrorCode::NoCompatibleMethod, "No compatible methods found".to_owned()));
         }
 
         let mut just_count_to_methods = Vec::new();
@@ -656,10 +842,10 @@ impl Ty {
                 return Ok(just_count_to_methods[i][0].clone());
             }
             else if just_count_to_methods[i].len() >= 2 {
-                return Err(ElementError::new("Too many compatible methods found".to_owned()))
+                return Err(ElementError::new(ErrorCode::NoCompatibleMethod, "Too many compatible methods found".to_owned()))
             }
         }
 
-        Err(ElementError::new("No compatible methods found".to_owned()))
+        Err(ElementError::new(ErrorCode::NoCompatibleMethod, "No compatible methods found".to_owned()))
     }
 }
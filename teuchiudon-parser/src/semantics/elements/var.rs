@@ -85,6 +85,14 @@ impl Var {
         value
     }
 
+    pub fn new_tmp<'input>(
+        context: &Context<'input>,
+        ty: Rc<Ty>,
+    ) -> Rc<Self> {
+        let name = format!("tmp[{}]", context.var_store.next_id());
+        Self::force_new(context, Qual::top(context), name, ty, true, None)
+    }
+
     pub fn get<'input>(
         context: &Context<'input>,
         qual: QualKey,
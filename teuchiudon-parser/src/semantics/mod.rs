@@ -1,18 +1,39 @@
 pub mod analyzer;
 pub mod ast;
+pub mod dataflow;
 pub mod elements;
 
+use crate::error::diagnostic::ErrorCode;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct SemanticError<'input> {
+    pub code: ErrorCode,
     pub slice: Option<&'input str>,
     pub message: String,
+    pub labels: Vec<(&'input str, String)>,
+    pub help: Option<String>,
 }
 
 impl<'input> SemanticError<'input> {
-    pub fn new(slice: Option<&'input str>, message: String) -> Self {
+    pub fn new(code: ErrorCode, slice: Option<&'input str>, message: String) -> Self {
         SemanticError {
+            code,
             slice,
             message,
+            labels: Vec::new(),
+            help: None,
+        }
+    }
+
+    pub fn with_label(mut self, slice: Option<&'input str>, message: String) -> Self {
+        if let Some(slice) = slice {
+            self.labels.push((slice, message));
         }
+        self
+    }
+
+    pub fn with_help(mut self, help: String) -> Self {
+        self.help = Some(help);
+        self
     }
 }
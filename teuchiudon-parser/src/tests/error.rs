@@ -1,12 +1,38 @@
+use itertools::Itertools;
 use nom_supreme::error::{
     BaseErrorKind,
     Expectation,
     GenericErrorTree,
     StackContext,
 };
+use crate::context::Context;
 use crate::error::{
+    NEWLINE,
     ErrorTree,
+    catalog::{
+        Locale,
+        translate,
+    },
+    diagnostic::{
+        Diagnostic,
+        ErrorCode,
+        Label,
+        Location,
+        Range,
+        Severity,
+    },
     line_infoes,
+    semantic_error::convert_semantic_error,
+};
+use crate::{
+    analize,
+    parse,
+    parse_partial,
+    warnings,
+};
+use crate::semantics::{
+    SemanticError,
+    elements::ev::Ev,
 };
 
 #[test]
@@ -153,3 +179,307 @@ fn test() {
         vec![(4, 4, &input[24..31], &input[27..29], &"b".to_owned()), (3, 3, &input[16..23], &input[18..20], &"a".to_owned())],
     );
 }
+
+#[test]
+fn test_location() {
+    let input = "ab\ncd\r\nef";
+    assert_eq!(Location::new(input, 0), Location { offset: 0, line: 1, column: 1, utf16_column: 1 });
+    assert_eq!(Location::new(input, 4), Location { offset: 4, line: 2, column: 2, utf16_column: 2 });
+    assert_eq!(Location::new(input, 7), Location { offset: 7, line: 3, column: 1, utf16_column: 1 });
+    assert_eq!(Range::from_slice(input, &input[2..5]), Some(Range::new(input, 3, 5)));
+    assert_eq!(Range::from_slice(input, "ab"), None);
+    let input = "\na\rb\r\nc";
+    assert_eq!(Location::new(input, 1), Location { offset: 1, line: 2, column: 1, utf16_column: 1 });
+    assert_eq!(Location::new(input, 3), Location { offset: 3, line: 3, column: 1, utf16_column: 1 });
+    assert_eq!(Location::new(input, 6), Location { offset: 6, line: 4, column: 1, utf16_column: 1 });
+}
+
+#[test]
+fn test_location_multibyte() {
+    let input = "let 名前 = 1;\nlet 𝑥 = 名前;";
+    let offset = input.find('=').unwrap();
+    assert_eq!(Location::new(input, offset), Location { offset, line: 1, column: 8, utf16_column: 8 });
+    let offset = input.rfind('=').unwrap();
+    assert_eq!(Location::new(input, offset), Location { offset, line: 2, column: 7, utf16_column: 8 });
+    let offset = input.rfind("名前").unwrap();
+    assert_eq!(Location::new(input, offset), Location { offset, line: 2, column: 9, utf16_column: 10 });
+}
+
+#[test]
+fn test_render_multibyte() {
+    let input = "let 名前 = 1;\r\nlet x = \"あいう\";";
+    let start = input.find("あいう").unwrap();
+    let diagnostic = Diagnostic::new(
+        Severity::Error,
+        ErrorCode::TypeMismatch,
+        "Mismatch".to_owned(),
+        Some(Range::new(input, start, start + "あいう".len())),
+    );
+    assert_eq!(
+        diagnostic.render(input),
+        format!("(2, 10): Mismatch{}let x = \"あいう\";{}{}^^^", NEWLINE, NEWLINE, " ".repeat(9)),
+    );
+}
+
+#[test]
+fn test_line_infoes_multibyte() {
+    let input = "名前:abc\r\n2:日本語";
+    let contexts = vec![
+        (&input[input.find('a').unwrap()..], StackContext::Context("a".to_owned())),
+        (&input[input.find('本').unwrap()..], StackContext::Context("b".to_owned())),
+    ];
+    let e = ErrorTree::Stack {
+        base: Box::new(
+            GenericErrorTree::Base {
+                location: &input[0..3],
+                kind: BaseErrorKind::Expected(Expectation::Something),
+            },
+        ),
+        contexts,
+    };
+    let infoes = line_infoes(input, &e);
+    assert_eq!(infoes.iter().map(|x| (x.0, x.1, x.2)).collect::<Vec<_>>(), vec![(2, 4, "2:日本語")]);
+}
+
+#[test]
+fn test_parsed_diagnostic_multibyte() {
+    let context = Context::new().unwrap();
+    let input = "let x = (\"名前\";";
+    let diagnostics = parse(&context, input).err().unwrap();
+    let range = diagnostics[0].range.unwrap();
+    assert_eq!((range.start.line, range.start.column, range.start.utf16_column), (1, 14, 14));
+    assert!(diagnostics[0].render(input).ends_with(&format!("{}^", " ".repeat(13))));
+}
+
+#[test]
+fn test_semantic_diagnostic() {
+    let input = "enum E { A, B, A };";
+    let e =
+        SemanticError::new(ErrorCode::Duplicated, Some(&input[15..16]), "Enum member `A` is duplicated".to_owned())
+        .with_label(Some(&input[9..10]), "First defined here".to_owned());
+    let diagnostics = convert_semantic_error(input, vec![e], Severity::Error);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic {
+            file: None,
+            severity: Severity::Error,
+            code: ErrorCode::Duplicated,
+            message: "Enum member `A` is duplicated".to_owned(),
+            range: Some(Range::new(input, 15, 16)),
+            labels: vec![Label { range: Range::new(input, 9, 10), message: "First defined here".to_owned() }],
+            help: None,
+        }],
+    );
+    assert_eq!(
+        diagnostics[0].render(input),
+        format!("(1, 16): Enum member `A` is duplicated{}{}{}{}^", NEWLINE, input, NEWLINE, " ".repeat(15)),
+    );
+}
+
+#[test]
+fn test_parsed_diagnostic() {
+    let context = Context::new().unwrap();
+    let input = "let x = (1;\nlet y = 2;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert!(diagnostics.iter().all(|x| x.code == ErrorCode::Syntax && x.severity == Severity::Error));
+    assert_eq!(diagnostics[0].range, Some(Range::new(input, 10, 11)));
+    assert_eq!(diagnostics[0].message, "Parse error, expected `)` or `,`, found `;`");
+}
+
+#[test]
+fn test_parsed_diagnostic_expected() {
+    let context = Context::new().unwrap();
+    let input = "let x = ;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Parse error, expected expression, found `;`");
+    assert_eq!(diagnostics[0].help, None);
+    let input = "let x = (1";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics[0].message, "Parse error, expected `)` or `,`, found end of file");
+    assert_eq!(diagnostics[0].range, Some(Range::new(input, 10, 10)));
+}
+
+#[test]
+fn test_parsed_diagnostic_dangling_op() {
+    let context = Context::new().unwrap();
+    let input = "let x = 1 +;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Parse error, expected expression, found `;`");
+    assert_eq!(diagnostics[0].range, Some(Range::new(input, 11, 12)));
+    let input = "fn f() { let x = 1 * 2 -; };\nlet y = 1;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Parse error, expected expression, found `;`");
+    assert_eq!(diagnostics[0].range, Some(Range::new(input, 24, 25)));
+}
+
+#[test]
+fn test_parsed_diagnostic_help() {
+    let context = Context::new().unwrap();
+    let input = "fn f() { }\nlet x = 1;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics[0].message, "Parse error, expected `;`, found `let`");
+    assert_eq!(diagnostics[0].help, Some("Add `;` after the closing `}` of the block".to_owned()));
+    assert!(diagnostics[0].render(input).ends_with("help: Add `;` after the closing `}` of the block"));
+    let input = "let x = 1\nlet y = 2;";
+    let diagnostics = parse(&context, input).err().unwrap();
+    assert_eq!(diagnostics[0].help, Some("Add `;` at the end of the previous line".to_owned()));
+}
+
+#[test]
+fn test_parse_partial() {
+    let context = Context::new().unwrap();
+    let input = "let x = (1;\nlet y = 2;\nfn f() { let z = ; };\n";
+    let (target, diagnostics) = parse_partial(&context, input);
+    assert_eq!(target.unwrap().body.as_ref().unwrap().top_stats.len(), 3);
+    assert_eq!(
+        diagnostics.iter().map(|x| x.range.unwrap().start.line).unique().collect::<Vec<_>>(),
+        vec![1, 3],
+    );
+}
+
+#[test]
+fn test_analize_recovery() {
+    let context = Context::new().unwrap();
+    let input = "let a = nope;\nlet (x, y) = a;\nlet c = missing;\n";
+    let parsed = parse(&context, input).unwrap();
+    let diagnostics = analize(&context, input, parsed).err().unwrap();
+    assert_eq!(
+        diagnostics.iter().map(|x| (x.range.unwrap().start.line, x.code)).collect::<Vec<_>>(),
+        vec![(1, ErrorCode::NotFound), (3, ErrorCode::NotFound)],
+    );
+}
+
+#[test]
+fn test_warnings() {
+    let context = Context::new().unwrap();
+    let input = "let a = ();\nlet b = a;\nfn f() { return; (); };\nlet b = ();\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    let diagnostics = warnings(&context, input);
+    assert!(diagnostics.iter().all(|x| x.severity == Severity::Warning));
+    assert_eq!(
+        diagnostics.iter().map(|x| (x.range.unwrap().start.line, x.code)).collect::<Vec<_>>(),
+        vec![
+            (3, ErrorCode::UnreachableCode),
+            (4, ErrorCode::ShadowedTopLevel),
+            (2, ErrorCode::UnusedVariable),
+            (3, ErrorCode::UnusedFunction),
+            (4, ErrorCode::UnusedVariable),
+        ],
+    );
+    assert_eq!(diagnostics[1].labels[0].range, Range::new(input, 16, 17));
+}
+
+#[test]
+fn test_possibly_uninitialized_warnings() {
+    let context = Context::new().unwrap();
+    for (name, real_name) in [("OnEnable", "_onEnable"), ("OnDeserialization", "_onDeserialization")] {
+        Ev::new(&context, name.to_owned(), Vec::new(), Vec::new(), real_name.to_owned(), Vec::new()).unwrap();
+    }
+    let input = "let a = ();\nlet b = ();\nlet c = ();\npub let d = 1;\nfn f() { b; };\npub fn OnEnable() { a; f(); d; };\npub fn OnDeserialization() { c; };\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    let diagnostics =
+        warnings(&context, input).into_iter()
+        .filter(|x| x.code == ErrorCode::PossiblyUninitialized)
+        .collect::<Vec<_>>();
+    assert!(diagnostics.iter().all(|x| x.severity == Severity::Warning));
+    assert_eq!(
+        diagnostics.iter().map(|x| (x.range.unwrap().start.line, x.message.clone())).collect::<Vec<_>>(),
+        vec![
+            (6, "Variable `a` may be read before it is initialized".to_owned()),
+            (5, "Variable `b` may be read before it is initialized".to_owned()),
+            (7, "Variable `c` may be read before it is initialized".to_owned()),
+        ],
+    );
+    assert_eq!(diagnostics[0].labels[0].range.start.line, 1);
+}
+
+#[test]
+fn test_possibly_uninitialized_assignments() {
+    let context = Context::new().unwrap();
+    Ev::new(&context, "OnEnable".to_owned(), Vec::new(), Vec::new(), "_onEnable".to_owned(), Vec::new()).unwrap();
+    let input = "let mut a = ();\nlet mut b = ();\nlet mut c = ();\nfn f() { c = (); };\npub fn OnEnable() { a = (); a; if true { b = (); }; b; f(); c; };\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    let diagnostics =
+        warnings(&context, input).into_iter()
+        .filter(|x| x.code == ErrorCode::PossiblyUninitialized)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics.iter().map(|x| x.message.clone()).collect::<Vec<_>>(),
+        vec!["Variable `b` may be read before it is initialized".to_owned()],
+    );
+}
+
+#[test]
+fn test_fn_call_not_discarded() {
+    let context = Context::new().unwrap();
+    let input = "fn f() { };\nf();\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    assert!(warnings(&context, input).is_empty());
+}
+
+#[test]
+fn test_qualified_fn_call_used() {
+    let context = Context::new().unwrap();
+    let input = "mod m { fn f() { }; };\nm::f();\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    assert!(warnings(&context, input).is_empty());
+}
+
+#[test]
+fn test_suppressed_warnings() {
+    let mut context = Context::new().unwrap();
+    context.options.suppressed_warnings = vec![ErrorCode::UnusedVariable];
+    let input = "let a = ();\nfn f() { };\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    assert_eq!(
+        warnings(&context, input).iter().map(|x| x.code).collect::<Vec<_>>(),
+        vec![ErrorCode::UnusedFunction],
+    );
+    assert_eq!("W0002".parse::<ErrorCode>(), Ok(ErrorCode::UnusedVariable));
+}
+
+#[test]
+fn test_translate() {
+    assert_eq!(
+        translate(Locale::Ja, ErrorCode::NotFound, "Specified variable `x` not found"),
+        "指定された変数 `x` が見つかりません".to_owned(),
+    );
+    assert_eq!(
+        translate(Locale::Ja, ErrorCode::Syntax, "Parse error, expected `;` or expression, found end of file"),
+        "構文エラー、`;` または 式 が必要ですが ファイルの終端 が見つかりました".to_owned(),
+    );
+    assert_eq!(
+        translate(Locale::Ja, ErrorCode::TypeMismatch, "Cannot be inferred type `T` from `int`"),
+        "`int` から型 `T` を推論できません".to_owned(),
+    );
+    assert_eq!(
+        translate(Locale::En, ErrorCode::NotFound, "Specified variable `x` not found"),
+        "Specified variable `x` not found".to_owned(),
+    );
+    assert_eq!(
+        translate(Locale::Ja, ErrorCode::Internal, "Specified variable `x` not found"),
+        "Specified variable `x` not found".to_owned(),
+    );
+    assert_eq!("ja".parse::<Locale>(), Ok(Locale::Ja));
+}
+
+#[test]
+fn test_localized_diagnostic() {
+    let context = Context::new().unwrap();
+    let input = "let a = ();\nlet a = ();\n";
+    let parsed = parse(&context, input).unwrap();
+    analize(&context, input, parsed).unwrap();
+    let diagnostics = warnings(&context, input).into_iter().map(|x| x.localized(Locale::Ja)).collect::<Vec<_>>();
+    assert_eq!(diagnostics[0].message, "変数 `a` がトップレベルの束縛を隠しています".to_owned());
+    assert_eq!(diagnostics[0].labels[0].message, "トップレベルの束縛はここで定義されています".to_owned());
+    assert_eq!(diagnostics[1].message, "変数 `a` は使用されていません".to_owned());
+}
@@ -81,6 +81,8 @@ fn test_ident() {
     assert_eq!(lexer::ident(&context)("1ab xxx").0.ok(), None);
     assert_eq!(lexer::ident(&context)("a_b xxx").0.ok(), Some((" xxx", Rc::new(ast::Ident { slice: "a_b" }))));
     assert_eq!(lexer::ident(&context)("_ab xxx").0.ok(), None);
+    assert_eq!(lexer::ident(&context)("let xxx").0.ok(), None);
+    assert_eq!(lexer::ident(&context)("custom xxx").0.ok(), Some((" xxx", Rc::new(ast::Ident { slice: "custom" }))));
 }
 
 #[test]
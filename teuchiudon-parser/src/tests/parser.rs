@@ -49,6 +49,7 @@ fn test_target() {
                         slice: " pub fn f() {};",
                         kind: Rc::new(ast::TopStatKind::FnBind {
                             access_attr: Some(Rc::new(ast::AccessAttr { slice: " pub", attr: Rc::new(lexer::ast::Keyword { slice: "pub", kind: lexer::ast::KeywordKind::Pub }) })),
+                            ev_attr: None,
                             fn_bind: Rc::new(ast::FnBind {
                                 slice: " fn f() {}",
                                 fn_keyword: Rc::new(lexer::ast::Keyword { slice: "fn", kind: lexer::ast::KeywordKind::Fn }),
@@ -116,6 +117,7 @@ fn test_body() {
                     slice: " pub fn f() {};",
                     kind: Rc::new(ast::TopStatKind::FnBind {
                         access_attr: Some(Rc::new(ast::AccessAttr { slice: " pub", attr: Rc::new(lexer::ast::Keyword { slice: "pub", kind: lexer::ast::KeywordKind::Pub }) })),
+                        ev_attr: None,
                         fn_bind: Rc::new(ast::FnBind {
                             slice: " fn f() {}",
                             fn_keyword: Rc::new(lexer::ast::Keyword { slice: "fn", kind: lexer::ast::KeywordKind::Fn }),
@@ -187,6 +189,7 @@ fn test_fn_bind_top_stat() {
             slice: "pub fn f(x: int) -> int { x };",
             kind: Rc::new(ast::TopStatKind::FnBind {
                 access_attr: Some(Rc::new(ast::AccessAttr { slice: "pub", attr: Rc::new(lexer::ast::Keyword { slice: "pub", kind: lexer::ast::KeywordKind::Pub })})),
+                ev_attr: None,
                 fn_bind: Rc::new(ast::FnBind {
                     slice: " fn f(x: int) -> int { x }",
                     fn_keyword: Rc::new(lexer::ast::Keyword { slice: "fn", kind: lexer::ast::KeywordKind::Fn }),
@@ -240,6 +243,93 @@ fn test_fn_bind_top_stat() {
     );
 }
 
+#[test]
+fn test_enum_bind_top_stat() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::top_stat(&context)("enum E { A };").ok(),
+        Some(("", Rc::new(ast::TopStat {
+            slice: "enum E { A };",
+            kind: Rc::new(ast::TopStatKind::EnumBind {
+                enum_bind: Rc::new(ast::EnumBind {
+                    slice: "enum E { A }",
+                    enum_keyword: Rc::new(lexer::ast::Keyword { slice: "enum", kind: lexer::ast::KeywordKind::Enum }),
+                    ident: Rc::new(lexer::ast::Ident { slice: "E" }),
+                    members: vec![Rc::new(lexer::ast::Ident { slice: "A" })],
+                }),
+            }),
+        }))),
+    );
+}
+
+#[test]
+fn test_newty_bind_top_stat() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::top_stat(&context)("newty T = int;").ok(),
+        Some(("", Rc::new(ast::TopStat {
+            slice: "newty T = int;",
+            kind: Rc::new(ast::TopStatKind::NewtyBind {
+                newty_bind: Rc::new(ast::NewtyBind {
+                    slice: "newty T = int",
+                    newty_keyword: Rc::new(lexer::ast::Keyword { slice: "newty", kind: lexer::ast::KeywordKind::Newty }),
+                    ident: Rc::new(lexer::ast::Ident { slice: "T" }),
+                    ty_expr: Rc::new(ast::TyExpr {
+                        slice: " int",
+                        ty_term: Rc::new(ast::TyTerm {
+                            slice: " int",
+                            kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "int" }) }),
+                        }),
+                        ty_ops: vec![],
+                    }),
+                    methods: vec![],
+                }),
+            }),
+        }))),
+    );
+}
+
+#[test]
+fn test_mod_bind_top_stat() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::top_stat(&context)("mod foo;").ok(),
+        Some(("", Rc::new(ast::TopStat {
+            slice: "mod foo;",
+            kind: Rc::new(ast::TopStatKind::ModBind {
+                mod_bind: Rc::new(ast::ModBind {
+                    slice: "mod foo",
+                    mod_keyword: Rc::new(lexer::ast::Keyword { slice: "mod", kind: lexer::ast::KeywordKind::Mod }),
+                    ident: Rc::new(lexer::ast::Ident { slice: "foo" }),
+                    top_stats: None,
+                }),
+            }),
+        }))),
+    );
+}
+
+#[test]
+fn test_use_bind_top_stat() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::top_stat(&context)("use foo::bar;").ok(),
+        Some(("", Rc::new(ast::TopStat {
+            slice: "use foo::bar;",
+            kind: Rc::new(ast::TopStatKind::UseBind {
+                use_bind: Rc::new(ast::UseBind {
+                    slice: "use foo::bar",
+                    use_keyword: Rc::new(lexer::ast::Keyword { slice: "use", kind: lexer::ast::KeywordKind::Use }),
+                    idents: vec![
+                        Rc::new(lexer::ast::Ident { slice: "foo" }),
+                        Rc::new(lexer::ast::Ident { slice: "bar" }),
+                    ],
+                    kind: Rc::new(ast::UseBindKind::Single),
+                }),
+            }),
+        }))),
+    );
+}
+
 #[test]
 fn test_stat_top_stat() {
     let context = Context::new().unwrap();
@@ -257,7 +347,7 @@ fn test_stat_top_stat() {
                                 slice: "f",
                                 kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                             }),
-                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                         }),
                     }),
                 })
@@ -498,7 +588,7 @@ fn test_fn_bind() {
                                     slice: " g",
                                     kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "g" }) }),
                                 }),
-                                ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                             }),
                         }),
                     }),
@@ -576,6 +666,185 @@ fn test_fn_decl() {
     );
 }
 
+#[test]
+fn test_enum_bind() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::enum_bind(&context)("enum State { Idle, Running, Dead }").ok(),
+        Some(("", Rc::new(ast::EnumBind {
+            slice: "enum State { Idle, Running, Dead }",
+            enum_keyword: Rc::new(lexer::ast::Keyword { slice: "enum", kind: lexer::ast::KeywordKind::Enum }),
+            ident: Rc::new(lexer::ast::Ident { slice: "State" }),
+            members: vec![
+                Rc::new(lexer::ast::Ident { slice: "Idle" }),
+                Rc::new(lexer::ast::Ident { slice: "Running" }),
+                Rc::new(lexer::ast::Ident { slice: "Dead" }),
+            ],
+        }))),
+    );
+    assert_eq!(
+        parser::enum_bind(&context)("enum State { Idle, }").ok(),
+        Some(("", Rc::new(ast::EnumBind {
+            slice: "enum State { Idle, }",
+            enum_keyword: Rc::new(lexer::ast::Keyword { slice: "enum", kind: lexer::ast::KeywordKind::Enum }),
+            ident: Rc::new(lexer::ast::Ident { slice: "State" }),
+            members: vec![
+                Rc::new(lexer::ast::Ident { slice: "Idle" }),
+            ],
+        }))),
+    );
+    assert_eq!(
+        parser::enum_bind(&context)("enum State {}").ok(),
+        None,
+    );
+}
+
+#[test]
+fn test_newty_bind() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::newty_bind(&context)("newty Score = int { CompareTo, Equals, }").ok(),
+        Some(("", Rc::new(ast::NewtyBind {
+            slice: "newty Score = int { CompareTo, Equals, }",
+            newty_keyword: Rc::new(lexer::ast::Keyword { slice: "newty", kind: lexer::ast::KeywordKind::Newty }),
+            ident: Rc::new(lexer::ast::Ident { slice: "Score" }),
+            ty_expr: Rc::new(ast::TyExpr {
+                slice: " int",
+                ty_term: Rc::new(ast::TyTerm {
+                    slice: " int",
+                    kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "int" }) }),
+                }),
+                ty_ops: vec![],
+            }),
+            methods: vec![
+                Rc::new(lexer::ast::Ident { slice: "CompareTo" }),
+                Rc::new(lexer::ast::Ident { slice: "Equals" }),
+            ],
+        }))),
+    );
+    assert_eq!(
+        parser::newty_bind(&context)("newty Score").ok(),
+        None,
+    );
+}
+
+#[test]
+fn test_mod_bind() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::mod_bind(&context)("mod foo { f(); }").ok(),
+        Some(("", Rc::new(ast::ModBind {
+            slice: "mod foo { f(); }",
+            mod_keyword: Rc::new(lexer::ast::Keyword { slice: "mod", kind: lexer::ast::KeywordKind::Mod }),
+            ident: Rc::new(lexer::ast::Ident { slice: "foo" }),
+            top_stats: Some(vec![
+                Rc::new(ast::TopStat {
+                    slice: " f();",
+                    kind: Rc::new(ast::TopStatKind::Stat {
+                        stat: Rc::new(ast::Stat {
+                            slice: " f()",
+                            kind: Rc::new(ast::StatKind::Expr {
+                                expr: Rc::new(ast::Expr {
+                                    slice: " f()",
+                                    term: Rc::new(ast::Term {
+                                        slice: " f",
+                                        kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
+                                    }),
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
+                                }),
+                            }),
+                        }),
+                    }),
+                }),
+            ]),
+        }))),
+    );
+    assert_eq!(
+        parser::mod_bind(&context)("mod foo {}").ok(),
+        Some(("", Rc::new(ast::ModBind {
+            slice: "mod foo {}",
+            mod_keyword: Rc::new(lexer::ast::Keyword { slice: "mod", kind: lexer::ast::KeywordKind::Mod }),
+            ident: Rc::new(lexer::ast::Ident { slice: "foo" }),
+            top_stats: Some(vec![]),
+        }))),
+    );
+}
+
+#[test]
+fn test_use_bind() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::use_bind(&context)("use foo::bar::baz").ok(),
+        Some(("", Rc::new(ast::UseBind {
+            slice: "use foo::bar::baz",
+            use_keyword: Rc::new(lexer::ast::Keyword { slice: "use", kind: lexer::ast::KeywordKind::Use }),
+            idents: vec![
+                Rc::new(lexer::ast::Ident { slice: "foo" }),
+                Rc::new(lexer::ast::Ident { slice: "bar" }),
+                Rc::new(lexer::ast::Ident { slice: "baz" }),
+            ],
+            kind: Rc::new(ast::UseBindKind::Single),
+        }))),
+    );
+    assert_eq!(
+        parser::use_bind(&context)("use foo::*").ok(),
+        Some(("", Rc::new(ast::UseBind {
+            slice: "use foo::*",
+            use_keyword: Rc::new(lexer::ast::Keyword { slice: "use", kind: lexer::ast::KeywordKind::Use }),
+            idents: vec![
+                Rc::new(lexer::ast::Ident { slice: "foo" }),
+            ],
+            kind: Rc::new(ast::UseBindKind::Glob {
+                op_code: Rc::new(lexer::ast::OpCode { slice: "*", kind: lexer::ast::OpCodeKind::Star }),
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::use_bind(&context)("use foo::bar as baz").ok(),
+        Some(("", Rc::new(ast::UseBind {
+            slice: "use foo::bar as baz",
+            use_keyword: Rc::new(lexer::ast::Keyword { slice: "use", kind: lexer::ast::KeywordKind::Use }),
+            idents: vec![
+                Rc::new(lexer::ast::Ident { slice: "foo" }),
+                Rc::new(lexer::ast::Ident { slice: "bar" }),
+            ],
+            kind: Rc::new(ast::UseBindKind::Alias {
+                as_keyword: Rc::new(lexer::ast::Keyword { slice: "as", kind: lexer::ast::KeywordKind::As }),
+                ident: Rc::new(lexer::ast::Ident { slice: "baz" }),
+            }),
+        }))),
+    );
+}
+
+#[test]
+fn test_ty_bind() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::ty_bind(&context)("ty Pair<T, U> = T").ok(),
+        Some(("", Rc::new(ast::TyBind {
+            slice: "ty Pair<T, U> = T",
+            ty_keyword: Rc::new(lexer::ast::Keyword { slice: "ty", kind: lexer::ast::KeywordKind::Ty }),
+            ident: Rc::new(lexer::ast::Ident { slice: "Pair" }),
+            ty_params: vec![
+                Rc::new(lexer::ast::Ident { slice: "T" }),
+                Rc::new(lexer::ast::Ident { slice: "U" }),
+            ],
+            ty_expr: Rc::new(ast::TyExpr {
+                slice: " T",
+                ty_term: Rc::new(ast::TyTerm {
+                    slice: " T",
+                    kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "T" }) }),
+                }),
+                ty_ops: vec![],
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::ty_bind(&context)("ty Pair<> = T").ok(),
+        None,
+    );
+}
+
 #[test]
 fn test_ty_expr() {
     let context = Context::new().unwrap();
@@ -629,6 +898,45 @@ fn test_ty_op() {
             }),
         }))),
     );
+    assert_eq!(
+        parser::ty_op(&context)("<T, U>").ok(),
+        Some(("", Rc::new(ast::TyOp {
+            slice: "<T, U>",
+            kind: Rc::new(ast::TyOpKind::Apply {
+                ty_exprs: vec![
+                    Rc::new(ast::TyExpr {
+                        slice: "T",
+                        ty_term: Rc::new(ast::TyTerm {
+                            slice: "T",
+                            kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "T" }) }),
+                        }),
+                        ty_ops: vec![],
+                    }),
+                    Rc::new(ast::TyExpr {
+                        slice: " U",
+                        ty_term: Rc::new(ast::TyTerm {
+                            slice: " U",
+                            kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "U" }) }),
+                        }),
+                        ty_ops: vec![],
+                    }),
+                ],
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::ty_op(&context)("?").ok(),
+        Some(("", Rc::new(ast::TyOp {
+            slice: "?",
+            kind: Rc::new(ast::TyOpKind::Nullable {
+                op_code: Rc::new(lexer::ast::OpCode { slice: "?", kind: lexer::ast::OpCodeKind::Question }),
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::ty_op(&context)("??").ok(),
+        None,
+    );
 }
 
 #[test]
@@ -668,7 +976,7 @@ fn test_stats_block() {
                                 slice: " f",
                                 kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                             }),
-                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                         }),
                     }),
                 }),
@@ -697,7 +1005,7 @@ fn test_stats_block() {
                                 slice: " f",
                                 kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                             }),
-                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                            ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                         }),
                     }),
                 }),
@@ -720,6 +1028,61 @@ fn test_stats_block() {
     );
 }
 
+#[test]
+fn test_stats_block_recovery() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::stats_block(&context)("{ let = 1; { f( }; x }").ok(),
+        Some(("", Rc::new(ast::StatsBlock {
+            slice: "{ let = 1; { f( }; x }",
+            stats: vec![
+                Rc::new(ast::Stat { slice: " let = 1;", kind: Rc::new(ast::StatKind::Error) }),
+                Rc::new(ast::Stat {
+                    slice: " { f( }",
+                    kind: Rc::new(ast::StatKind::Expr {
+                        expr: Rc::new(ast::Expr {
+                            slice: " { f( }",
+                            term: Rc::new(ast::Term {
+                                slice: " { f( }",
+                                kind: Rc::new(ast::TermKind::Block {
+                                    stats: Rc::new(ast::StatsBlock {
+                                        slice: " { f( }",
+                                        stats: vec![Rc::new(ast::Stat { slice: " f(", kind: Rc::new(ast::StatKind::Error) })],
+                                        ret: None,
+                                    }),
+                                }),
+                            }),
+                            ops: vec![],
+                        }),
+                    }),
+                }),
+            ],
+            ret: Some(Rc::new(ast::Expr {
+                slice: " x",
+                term: Rc::new(ast::Term {
+                    slice: " x",
+                    kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "x" }) }),
+                }),
+                ops: vec![],
+            })),
+        }))),
+    );
+    assert_eq!(context.parse_errors.borrow().len(), 2);
+    assert_eq!(parser::stats_block(&context)("{ let = 1;").ok(), None);
+}
+
+#[test]
+fn test_body_recovery() {
+    let context = Context::new().unwrap();
+    let (rest, body) = parser::body(&context)("let x = ; } \"};\" x; enum E { A };").unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(
+        body.top_stats.iter().map(|x| (x.slice, matches!(x.kind.as_ref(), ast::TopStatKind::Error))).collect::<Vec<_>>(),
+        vec![("let x = ;", true), (" }", true), (" \"};\" x;", true), (" enum E { A };", false)],
+    );
+    assert_eq!(context.parse_errors.borrow().len(), 3);
+}
+
 #[test]
 fn test_return_stat() {
     let context = Context::new().unwrap();
@@ -982,6 +1345,33 @@ fn test_fn_bind_stat() {
     );
 }
 
+#[test]
+fn test_ty_bind_stat() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::stat(&context)("ty T = int;").ok(),
+        Some(("", Rc::new(ast::Stat {
+            slice: "ty T = int",
+            kind: Rc::new(ast::StatKind::TyBind {
+                ty_bind: Rc::new(ast::TyBind {
+                    slice: "ty T = int",
+                    ty_keyword: Rc::new(lexer::ast::Keyword { slice: "ty", kind: lexer::ast::KeywordKind::Ty }),
+                    ident: Rc::new(lexer::ast::Ident { slice: "T" }),
+                    ty_params: vec![],
+                    ty_expr: Rc::new(ast::TyExpr {
+                        slice: " int",
+                        ty_term: Rc::new(ast::TyTerm {
+                            slice: " int",
+                            kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "int" }) }),
+                        }),
+                        ty_ops: vec![],
+                    }),
+                }),
+            }),
+        }))),
+    );
+}
+
 #[test]
 fn test_expr_stat() {
     let context = Context::new().unwrap();
@@ -1048,7 +1438,7 @@ fn test_expr() {
                 }),
                 Rc::new(ast::Op {
                     slice: "(1, 2)",
-                    kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![
+                    kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![
                         Rc::new(ast::ArgExpr {
                             slice: "1",
                             mut_attr: None,
@@ -1194,13 +1584,13 @@ fn test_eval_fn_op() {
     let context = Context::new().unwrap();
     assert_eq!(
         parser::op(&context)("()").ok(),
-        Some(("", Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) }))),
+        Some(("", Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) }))),
     );
     assert_eq!(
         parser::op(&context)("(mut x, y, z)").ok(),
         Some(("", Rc::new(ast::Op {
             slice: "(mut x, y, z)",
-            kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![
+            kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![
                 Rc::new(ast::ArgExpr {
                     slice: "mut x",
                     mut_attr: Some(Rc::new(ast::MutAttr { slice: "mut", attr: Rc::new(lexer::ast::Keyword { slice: "mut", kind: lexer::ast::KeywordKind::Mut }) })),
@@ -1244,7 +1634,7 @@ fn test_eval_fn_op() {
         parser::op(&context)("(mut x, y, z,)").ok(),
         Some(("", Rc::new(ast::Op {
             slice: "(mut x, y, z,)",
-            kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![
+            kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![
                 Rc::new(ast::ArgExpr {
                     slice: "mut x",
                     mut_attr: Some(Rc::new(ast::MutAttr { slice: "mut", attr: Rc::new(lexer::ast::Keyword { slice: "mut", kind: lexer::ast::KeywordKind::Mut }) })),
@@ -1284,6 +1674,38 @@ fn test_eval_fn_op() {
             ]}),
         }))),
     );
+    assert_eq!(
+        parser::op(&context)("::<T>(x)").ok(),
+        Some(("", Rc::new(ast::Op {
+            slice: "::<T>(x)",
+            kind: Rc::new(ast::OpKind::EvalFn {
+                ty_exprs: vec![
+                    Rc::new(ast::TyExpr {
+                        slice: "T",
+                        ty_term: Rc::new(ast::TyTerm {
+                            slice: "T",
+                            kind: Rc::new(ast::TyTermKind::EvalTy { ident: Rc::new(lexer::ast::Ident { slice: "T" }) }),
+                        }),
+                        ty_ops: vec![],
+                    }),
+                ],
+                arg_exprs: vec![
+                    Rc::new(ast::ArgExpr {
+                        slice: "x",
+                        mut_attr: None,
+                        expr: Rc::new(ast::Expr {
+                            slice: "x",
+                            term: Rc::new(ast::Term {
+                                slice: "x",
+                                kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "x" }) }),
+                            }),
+                            ops: vec![],
+                        }),
+                    }),
+                ],
+            }),
+        }))),
+    );
 }
 
 #[test]
@@ -1731,7 +2153,7 @@ fn test_block_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -1744,7 +2166,7 @@ fn test_block_term() {
                                         slice: " g",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "g" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -1778,7 +2200,7 @@ fn test_block_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -1791,7 +2213,7 @@ fn test_block_term() {
                                         slice: " g",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "g" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2384,7 +2806,7 @@ fn test_if_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2434,7 +2856,7 @@ fn test_if_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2455,7 +2877,7 @@ fn test_if_term() {
                                             slice: " g",
                                             kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "g" }) }),
                                         }),
-                                        ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                        ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                     }),
                                 }),
                             }),
@@ -2505,7 +2927,7 @@ fn test_if_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2556,7 +2978,7 @@ fn test_if_term() {
                                                             slice: " g",
                                                             kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "g" }) }),
                                                         }),
-                                                        ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                                        ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                                     }),
                                                 }),
                                             }),
@@ -2575,6 +2997,68 @@ fn test_if_term() {
     );
 }
 
+#[test]
+fn test_match_term() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::term(&context)("match x { 0 => a, _ => b, }").ok(),
+        Some(("", Rc::new(ast::Term {
+            slice: "match x { 0 => a, _ => b, }",
+            kind: Rc::new(ast::TermKind::Match {
+                match_keyword: Rc::new(lexer::ast::Keyword { slice: "match", kind: lexer::ast::KeywordKind::Match }),
+                expr: Rc::new(ast::Expr {
+                    slice: " x",
+                    term: Rc::new(ast::Term {
+                        slice: " x",
+                        kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "x" }) }),
+                    }),
+                    ops: vec![],
+                }),
+                match_arms: vec![
+                    Rc::new(ast::MatchArm {
+                        slice: " 0 => a",
+                        pattern: Rc::new(ast::Pattern {
+                            slice: " 0",
+                            kind: Rc::new(ast::PatternKind::Literal {
+                                literal: Rc::new(lexer::ast::Literal { slice: "0", kind: Rc::new(lexer::ast::LiteralKind::PureInteger { slice: "0" }) }),
+                            }),
+                        }),
+                        expr: Rc::new(ast::Expr {
+                            slice: " a",
+                            term: Rc::new(ast::Term {
+                                slice: " a",
+                                kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "a" }) }),
+                            }),
+                            ops: vec![],
+                        }),
+                    }),
+                    Rc::new(ast::MatchArm {
+                        slice: " _ => b",
+                        pattern: Rc::new(ast::Pattern {
+                            slice: " _",
+                            kind: Rc::new(ast::PatternKind::Wildcard {
+                                op_code: Rc::new(lexer::ast::OpCode { slice: "_", kind: lexer::ast::OpCodeKind::Wildcard }),
+                            }),
+                        }),
+                        expr: Rc::new(ast::Expr {
+                            slice: " b",
+                            term: Rc::new(ast::Term {
+                                slice: " b",
+                                kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "b" }) }),
+                            }),
+                            ops: vec![],
+                        }),
+                    }),
+                ],
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::term(&context)("match x { }").ok(),
+        None,
+    );
+}
+
 #[test]
 fn test_while_term() {
     let context = Context::new().unwrap();
@@ -2617,7 +3101,7 @@ fn test_while_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2650,7 +3134,7 @@ fn test_loop_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2807,7 +3291,7 @@ fn test_for_term() {
                                         slice: " f",
                                         kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "f" }) }),
                                     }),
-                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { arg_exprs: vec![] }) })],
+                                    ops: vec![Rc::new(ast::Op { slice: "()", kind: Rc::new(ast::OpKind::EvalFn { ty_exprs: vec![], arg_exprs: vec![] }) })],
                                 }),
                             }),
                         }),
@@ -2950,6 +3434,89 @@ fn test_closure_term() {
     );
 }
 
+#[test]
+fn test_match_arm() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::match_arm(&context)("1 => a").ok(),
+        Some(("", Rc::new(ast::MatchArm {
+            slice: "1 => a",
+            pattern: Rc::new(ast::Pattern {
+                slice: "1",
+                kind: Rc::new(ast::PatternKind::Literal {
+                    literal: Rc::new(lexer::ast::Literal { slice: "1", kind: Rc::new(lexer::ast::LiteralKind::PureInteger { slice: "1" }) }),
+                }),
+            }),
+            expr: Rc::new(ast::Expr {
+                slice: " a",
+                term: Rc::new(ast::Term {
+                    slice: " a",
+                    kind: Rc::new(ast::TermKind::EvalVar { ident: Rc::new(lexer::ast::Ident { slice: "a" }) }),
+                }),
+                ops: vec![],
+            }),
+        }))),
+    );
+}
+
+#[test]
+fn test_pattern() {
+    let context = Context::new().unwrap();
+    assert_eq!(
+        parser::pattern(&context)("_").ok(),
+        Some(("", Rc::new(ast::Pattern {
+            slice: "_",
+            kind: Rc::new(ast::PatternKind::Wildcard {
+                op_code: Rc::new(lexer::ast::OpCode { slice: "_", kind: lexer::ast::OpCodeKind::Wildcard }),
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::pattern(&context)("0..9").ok(),
+        Some(("", Rc::new(ast::Pattern {
+            slice: "0..9",
+            kind: Rc::new(ast::PatternKind::Range {
+                left: Rc::new(lexer::ast::Literal { slice: "0", kind: Rc::new(lexer::ast::LiteralKind::PureInteger { slice: "0" }) }),
+                right: Rc::new(lexer::ast::Literal { slice: "9", kind: Rc::new(lexer::ast::LiteralKind::PureInteger { slice: "9" }) }),
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::pattern(&context)("(1, _)").ok(),
+        Some(("", Rc::new(ast::Pattern {
+            slice: "(1, _)",
+            kind: Rc::new(ast::PatternKind::Tuple {
+                patterns: vec![
+                    Rc::new(ast::Pattern {
+                        slice: "1",
+                        kind: Rc::new(ast::PatternKind::Literal {
+                            literal: Rc::new(lexer::ast::Literal { slice: "1", kind: Rc::new(lexer::ast::LiteralKind::PureInteger { slice: "1" }) }),
+                        }),
+                    }),
+                    Rc::new(ast::Pattern {
+                        slice: " _",
+                        kind: Rc::new(ast::PatternKind::Wildcard {
+                            op_code: Rc::new(lexer::ast::OpCode { slice: "_", kind: lexer::ast::OpCodeKind::Wildcard }),
+                        }),
+                    }),
+                ],
+            }),
+        }))),
+    );
+    assert_eq!(
+        parser::pattern(&context)("State::Idle").ok(),
+        Some(("", Rc::new(ast::Pattern {
+            slice: "State::Idle",
+            kind: Rc::new(ast::PatternKind::EvalVar {
+                idents: vec![
+                    Rc::new(lexer::ast::Ident { slice: "State" }),
+                    Rc::new(lexer::ast::Ident { slice: "Idle" }),
+                ],
+            }),
+        }))),
+    );
+}
+
 #[test]
 fn test_range_iter_expr() {
     let context = Context::new().unwrap();
@@ -9,6 +9,7 @@ use crate::semantics::{
         self,
         base_ty:: BaseTy,
         element::ValueElement,
+        ev::Ev,
         qual::Qual,
         ty::Ty,
     },
@@ -67,6 +68,8 @@ fn test_ty_expr() {
                                 term: Rc::new(ast::TyTerm {
                                     parsed: match parsed.ty_ops[0].kind.as_ref() {
                                         parser::ast::TyOpKind::Access { op_code: _, ty_term } => Some(ty_term.clone()),
+                                        parser::ast::TyOpKind::Apply { ty_exprs: _ } => None,
+                                        parser::ast::TyOpKind::Nullable { op_code: _ } => None,
                                     },
                                     detail: Rc::new(ast::TyTermDetail::EvalTy {
                                         ident: Rc::new(ast::Ident {
@@ -89,6 +92,8 @@ fn test_ty_expr() {
                         term: Rc::new(ast::TyTerm {
                             parsed: match parsed.ty_ops[1].kind.as_ref() {
                                 parser::ast::TyOpKind::Access { op_code: _, ty_term } => Some(ty_term.clone()),
+                                parser::ast::TyOpKind::Apply { ty_exprs: _ } => None,
+                                parser::ast::TyOpKind::Nullable { op_code: _ } => None,
                             },
                             detail: Rc::new(ast::TyTermDetail::EvalTy {
                                 ident: Rc::new(ast::Ident {
@@ -106,3 +111,15 @@ fn test_ty_expr() {
         })),
     )
 }
+
+#[test]
+fn test_ev_closest() {
+    let context = Context::new().unwrap();
+    for (name, real_name) in [("Start", "_start"), ("OnEnable", "_onEnable"), ("OnPlayerJoined", "_onPlayerJoined")] {
+        Ev::new(&context, name.to_owned(), Vec::new(), Vec::new(), real_name.to_owned(), Vec::new()).unwrap();
+    }
+    assert_eq!(Ev::closest(&context, "start").map(|x| x.name.clone()), Some("Start".to_owned()));
+    assert_eq!(Ev::closest(&context, "OnEnabled").map(|x| x.name.clone()), Some("OnEnable".to_owned()));
+    assert_eq!(Ev::closest(&context, "OnPlayerJoin").map(|x| x.name.clone()), Some("OnPlayerJoined".to_owned()));
+    assert_eq!(Ev::closest(&context, "Update").map(|x| x.name.clone()), None);
+}
@@ -4,10 +4,29 @@ use serde_json;
 #[derive(Debug, Deserialize)]
 pub struct Compiled {
     pub output: String,
-    pub errors: Vec<String>,
+    pub errors: Vec<Diagnostic>,
+    pub warnings: Vec<Diagnostic>,
     pub default_values: Vec<DefaultValue>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Diagnostic {
+    pub code: String,
+    #[cfg(test)]
+    pub message: String,
+    #[cfg(test)]
+    pub labels: Vec<Label>,
+    #[cfg(test)]
+    pub help: Option<String>,
+    pub rendered: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+pub struct Label {
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DefaultValue {
     pub name: String,
@@ -18,3 +37,7 @@ pub struct DefaultValue {
 pub fn from_json(json: &str) -> Compiled {
     serde_json::from_str(json).unwrap()
 }
+
+pub fn rendered(diagnostics: &[Diagnostic]) -> String {
+    diagnostics.iter().map(|x| format!("{}: {}", x.code, x.rendered)).collect::<Vec<_>>().join("\n")
+}
@@ -4,27 +4,90 @@ mod json;
 mod tests;
 
 use std::{
+    collections::BTreeMap,
     env,
     fs,
+    path::Path,
+    process,
+    str::FromStr,
 };
-use teuchiudon_compiler::compile;
+use teuchiudon_compiler::{
+    CompileOptions,
+    ErrorCode,
+    Locale,
+    compile_files_with_options,
+    compile_with_options,
+};
+
+fn parse_arg<T: FromStr<Err = String>>(arg: &str) -> T {
+    arg.parse::<T>().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    })
+}
 
 fn main() {
-    if env::args().len() != 2 {
-        panic!("Please specify .teuchi file path");
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if let Some(code) = args.iter().find_map(|x| x.strip_prefix("--explain=")) {
+        println!("{}", parse_arg::<ErrorCode>(code).explain().render());
+        return;
+    }
+    let null_safety = args.iter().any(|x| x == "--null-safety");
+    let suppressed_warnings =
+        args.iter()
+        .filter_map(|x| x.strip_prefix("--allow="))
+        .map(parse_arg::<ErrorCode>)
+        .collect();
+    let locale =
+        args.iter()
+        .find_map(|x| x.strip_prefix("--locale="))
+        .map_or(Locale::default(), parse_arg::<Locale>);
+    let paths = args.iter().filter(|x| !x.starts_with("--")).collect::<Vec<_>>();
+    if paths.len() != 1 {
+        eprintln!("Please specify .teuchi file path or module directory path");
+        eprintln!("Usage: teuchiudon-test [--null-safety] [--allow=<code>]... [--locale=<locale>] <path>");
+        eprintln!("       teuchiudon-test --explain=<code>");
+        process::exit(1);
     }
 
-    let path = env::args().last().unwrap();
-    let input = fs::read_to_string(path).unwrap();
+    let path = paths[0];
+    if !Path::new(path).exists() {
+        eprintln!("Path `{}` not found", path);
+        process::exit(1);
+    }
+    let options = CompileOptions { null_safety, suppressed_warnings, locale };
     let json = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
-    let output = compile(input.as_str(), json.as_str());
+    let output =
+        if Path::new(path).is_dir() {
+            let files = read_modules(Path::new(path), "");
+            compile_files_with_options(&files, "main.teuchi", json.as_str(), &options)
+        }
+        else {
+            let input = fs::read_to_string(path).unwrap();
+            compile_with_options(input.as_str(), json.as_str(), &options)
+        };
     let compiled = json::from_json(&output);
 
     if compiled.errors.len() == 0 {
         println!("{}", compiled.output);
         println!("{:#?}", compiled.default_values);
+        println!("{}", json::rendered(&compiled.warnings));
     }
     else {
-        println!("{}", compiled.errors.join("\n"));
+        println!("{}", json::rendered(&compiled.errors));
+    }
+}
+
+fn read_modules(path: &Path, prefix: &str) -> BTreeMap<String, String> {
+    let mut modules = BTreeMap::new();
+    for path in fs::read_dir(path).unwrap().map(|x| x.unwrap().path()) {
+        let name = format!("{}{}", prefix, path.file_name().unwrap().to_str().unwrap());
+        if path.is_dir() {
+            modules.extend(read_modules(&path, &format!("{}/", name)));
+        }
+        else if path.extension().is_some_and(|x| x == "teuchi") {
+            modules.insert(name, fs::read_to_string(&path).unwrap());
+        }
     }
+    modules
 }
@@ -1,11 +1,18 @@
 mod vm;
 
 use std::{
+    collections::BTreeMap,
     fs,
     path::Path,
 };
 use rstest::rstest;
-use teuchiudon_compiler::compile;
+use teuchiudon_compiler::{
+    CompileOptions,
+    ErrorCode,
+    Locale,
+    compile_files_with_options,
+    compile_with_options,
+};
 use crate::json;
 use self::vm::VM;
 
@@ -13,36 +20,147 @@ use self::vm::VM;
 struct TestCase {
     path: String,
     src: String,
+    modules: BTreeMap<String, String>,
     expected: Expected,
 }
 
 #[derive(Debug)]
 enum Expected {
-    Err,
+    Err(Option<String>),
     None,
     Some(String),
 }
 
 #[rstest]
+#[case::assign("./src/tests/teuchi/assign")]
+#[case::conversion("./src/tests/teuchi/conversion")]
 #[case::function("./src/tests/teuchi/function")]
+#[case::enum_bind("./src/tests/teuchi/enum_bind")]
 #[case::general("./src/tests/teuchi/general")]
+#[case::if_term("./src/tests/teuchi/if")]
+#[case::inference("./src/tests/teuchi/inference")]
 #[case::let_bind("./src/tests/teuchi/let_bind")]
+#[case::literal("./src/tests/teuchi/literal")]
+#[case::match_term("./src/tests/teuchi/match")]
+#[case::method("./src/tests/teuchi/method")]
+#[case::module("./src/tests/teuchi/module")]
+#[case::newty("./src/tests/teuchi/newty")]
+#[case::nullable("./src/tests/teuchi/nullable")]
+#[case::recovery("./src/tests/teuchi/recovery")]
 #[case::scope("./src/tests/teuchi/scope")]
+#[case::symbols("./src/tests/teuchi/symbols")]
+#[case::syntax("./src/tests/teuchi/syntax")]
+#[case::this_literal("./src/tests/teuchi/this")]
 #[case::top_stat("./src/tests/teuchi/top_stat")]
+#[case::ty_alias("./src/tests/teuchi/ty_alias")]
+#[case::use_bind("./src/tests/teuchi/use_bind")]
 fn test_teuchi(#[case] path: &str) {
+    run_teuchi(path, &CompileOptions::default());
+}
+
+#[rstest]
+#[case::null_safety("./src/tests/teuchi/null_safety")]
+fn test_teuchi_null_safety(#[case] path: &str) {
+    run_teuchi(path, &CompileOptions { null_safety: true, ..Default::default() });
+}
+
+#[test]
+fn test_explanations() {
+    let symbols = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
+    let options = CompileOptions { null_safety: true, ..Default::default() };
+    for code in ErrorCode::ALL {
+        let explanation = code.explain();
+        if let Some(failing) = explanation.failing {
+            let compiled = json::from_json(&compile_with_options(failing, &symbols, &options));
+            assert!(
+                compiled.errors.iter().chain(compiled.warnings.iter()).any(|x| x.code == code.as_str()),
+                "In the erroneous example of `{}`: not reported\n{}\n{}\n", code, json::rendered(&compiled.errors), json::rendered(&compiled.warnings),
+            );
+        }
+        if let Some(fixed) = explanation.fixed {
+            let compiled = json::from_json(&compile_with_options(fixed, &symbols, &options));
+            assert!(
+                compiled.errors.is_empty() && compiled.warnings.iter().all(|x| x.code != code.as_str()),
+                "In the fixed example of `{}`: still reported\n{}\n{}\n", code, json::rendered(&compiled.errors), json::rendered(&compiled.warnings),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ja_translations() {
+    let symbols = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
+    let mut untranslated = Vec::new();
+    for dir in fs::read_dir("./src/tests/teuchi").unwrap().map(|x| x.unwrap().path()).filter(|x| x.is_dir()) {
+        let null_safety = dir.file_name().is_some_and(|x| x == "null_safety");
+        for test_case in find_teuchi(Vec::new(), &dir) {
+            let en = compile_test_case(&test_case, &symbols, &CompileOptions { null_safety, ..Default::default() });
+            let ja = compile_test_case(&test_case, &symbols, &CompileOptions { null_safety, locale: Locale::Ja, ..Default::default() });
+            untranslated.extend(untranslated_messages(&test_case.path, &en, &ja));
+        }
+    }
+    for code in ErrorCode::ALL {
+        if let Some(failing) = code.explain().failing {
+            let en = json::from_json(&compile_with_options(failing, &symbols, &CompileOptions { null_safety: true, ..Default::default() }));
+            let ja = json::from_json(&compile_with_options(failing, &symbols, &CompileOptions { null_safety: true, locale: Locale::Ja, ..Default::default() }));
+            untranslated.extend(untranslated_messages(&format!("--explain={}", code), &en, &ja));
+        }
+    }
+    assert!(untranslated.is_empty(), "Not translated into ja\n{}\n", untranslated.join("\n"));
+}
+
+#[test]
+fn test_non_exhaustive_match_warning() {
+    let symbols = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
+    let src = "enum E { A, B };\nlet e = E::A;\nlet x = match e { E::A => 1 };\nlet y = match e { E::A => 1, _ => 2 };\nUnityEngine::Debug::Log(x);\nUnityEngine::Debug::Log(y);\n";
+    let compiled = json::from_json(&compile_with_options(src, &symbols, &CompileOptions::default()));
+    assert!(compiled.errors.is_empty(), "{}", json::rendered(&compiled.errors));
+    let non_exhaustive =
+        compiled.warnings.iter()
+        .filter(|x| x.code == ErrorCode::NonExhaustiveMatch.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(non_exhaustive.len(), 1, "{}", json::rendered(&compiled.warnings));
+    assert!(non_exhaustive[0].rendered.contains("let x = match e"), "{}", non_exhaustive[0].rendered);
+}
+
+#[test]
+fn test_unused_mod_file_warning() {
+    let symbols = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
+    let path = Path::new("./src/tests/teuchi/module/file_module_unused");
+    let compiled = json::from_json(&compile_files_with_options(&read_modules(path), "main.teuchi", &symbols, &CompileOptions::default()));
+    assert!(compiled.errors.is_empty(), "{}", json::rendered(&compiled.errors));
+    let unused =
+        compiled.warnings.iter()
+        .filter(|x| x.code == ErrorCode::UnusedModFile.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(unused.len(), 1, "{}", json::rendered(&compiled.warnings));
+    assert!(unused[0].rendered.starts_with("other.teuchi"), "{}", unused[0].rendered);
+}
+
+fn run_teuchi(path: &str, options: &CompileOptions) {
     let v = Vec::new();
     let test_cases = find_teuchi(v, Path::new(path));
 
     let symbols = fs::read_to_string("./src/tests/teuchi/udon-symbols.json").unwrap();
 
     for test_case in test_cases {
-        let json = compile(&test_case.src, &symbols);
-        let compiled = json::from_json(&json);
+        let compiled = compile_test_case(&test_case, &symbols, options);
 
-        if compiled.errors.len() != 0 && matches!(test_case.expected, Expected::Err) {
-            continue;
+        if compiled.errors.len() != 0 && matches!(test_case.expected, Expected::Err(_)) {
+            let Expected::Err(Some(code)) = &test_case.expected
+                else {
+                    continue;
+                };
+
+            if compiled.errors.iter().any(|x| &x.code == code) {
+                continue;
+            }
+            else {
+                let errors = json::rendered(&compiled.errors);
+                panic!("In \"{}\": actual compile error without `{}`\n{}\n", test_case.path, code, errors);
+            }
         }
-        else if compiled.errors.len() == 0 && !matches!(test_case.expected, Expected::Err) {
+        else if compiled.errors.len() == 0 && !matches!(test_case.expected, Expected::Err(_)) {
             let mut vm = VM::new(compiled.output.clone(), compiled.default_values);
             vm.run("_start");
 
@@ -69,16 +187,47 @@ fn test_teuchi(#[case] path: &str) {
                 panic!("In \"{}\": multiple logs exist\n{}", test_case.path, compiled.output);
             }
         }
-        else if compiled.errors.len() == 0 && matches!(test_case.expected, Expected::Err) {
+        else if compiled.errors.len() == 0 && matches!(test_case.expected, Expected::Err(_)) {
             panic!("In \"{}\": actual compiled, expected compile error\n{}", test_case.path, compiled.output);
         }
         else {
-            let errors = compiled.errors.join("\n");
+            let errors = json::rendered(&compiled.errors);
             panic!("In \"{}\": actual compile error, expected `{:?}`\n{}\n", test_case.path, test_case.expected, errors);
         }
     }
 }
 
+fn compile_test_case(test_case: &TestCase, symbols: &str, options: &CompileOptions) -> json::Compiled {
+    let json =
+        if test_case.modules.is_empty() {
+            compile_with_options(&test_case.src, symbols, options)
+        }
+        else {
+            let mut files = test_case.modules.clone();
+            files.insert("main.teuchi".to_owned(), test_case.src.clone());
+            compile_files_with_options(&files, "main.teuchi", symbols, options)
+        };
+    json::from_json(&json)
+}
+
+fn untranslated_messages(path: &str, en: &json::Compiled, ja: &json::Compiled) -> Vec<String> {
+    let messages = |x: &json::Diagnostic|
+        Some(x.message.clone()).into_iter()
+        .chain(x.labels.iter().map(|x| x.message.clone()))
+        .chain(x.help.clone())
+        .collect::<Vec<_>>();
+    en.errors.iter().chain(en.warnings.iter())
+    .zip(ja.errors.iter().chain(ja.warnings.iter()))
+    .flat_map(|(en, ja)|
+        messages(en).into_iter()
+        .zip(messages(ja))
+        .filter(|(en_message, ja_message)| en_message == ja_message)
+        .map(|(en_message, _)| format!("In \"{}\": {}: {}", path, en.code, en_message))
+        .collect::<Vec<_>>()
+    )
+    .collect()
+}
+
 fn find_teuchi(mut test_cases: Vec<TestCase>, path: &Path) -> Vec<TestCase> {
     let entries = fs::read_dir(path).unwrap();
     for entry in entries {
@@ -91,6 +240,19 @@ fn find_teuchi(mut test_cases: Vec<TestCase>, path: &Path) -> Vec<TestCase> {
             test_cases.push(TestCase {
                 path: path.to_str().unwrap().to_owned(),
                 src,
+                modules: BTreeMap::new(),
+                expected,
+            })
+        }
+        else if file_type.is_dir() {
+            let path = entry.path();
+            let mut modules = read_modules(&path);
+            let src = modules.remove("main.teuchi").unwrap();
+            let expected = get_expected(&src);
+            test_cases.push(TestCase {
+                path: path.to_str().unwrap().to_owned(),
+                src,
+                modules,
                 expected,
             })
         }
@@ -98,6 +260,24 @@ fn find_teuchi(mut test_cases: Vec<TestCase>, path: &Path) -> Vec<TestCase> {
     test_cases
 }
 
+fn read_modules(path: &Path) -> BTreeMap<String, String> {
+    read_modules_in(path, "")
+}
+
+fn read_modules_in(path: &Path, prefix: &str) -> BTreeMap<String, String> {
+    let mut modules = BTreeMap::new();
+    for path in fs::read_dir(path).unwrap().map(|x| x.unwrap().path()) {
+        let name = format!("{}{}", prefix, path.file_name().unwrap().to_str().unwrap());
+        if path.is_dir() {
+            modules.extend(read_modules_in(&path, &format!("{}/", name)));
+        }
+        else if path.extension().is_some_and(|x| x == "teuchi") {
+            modules.insert(name, fs::read_to_string(&path).unwrap());
+        }
+    }
+    modules
+}
+
 fn get_expected(src: &str) -> Expected {
     let line = src.lines().next();
     assert!(line.is_some());
@@ -105,8 +285,11 @@ fn get_expected(src: &str) -> Expected {
     assert!(line.starts_with("//"));
     let line = line.chars().skip(2).collect::<String>().trim().to_owned();
     match line.as_str() {
-        "!" => Expected::Err,
         "?" => Expected::None,
+        _ if line.starts_with('!') => {
+            let code = line[1..].trim();
+            Expected::Err(if code.is_empty() { None } else { Some(code.to_owned()) })
+        },
         _ => Expected::Some(line),
     }
 }
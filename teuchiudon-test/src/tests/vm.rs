@@ -253,14 +253,121 @@ impl VM {
                 let value = &self.var_values[&var];
                 self.logs.push(value.to_owned());
             },
+            "SystemInt32.__op_Equality__SystemInt32_SystemInt32__SystemBoolean" |
+            "SystemBoolean.__op_Equality__SystemBoolean_SystemBoolean__SystemBoolean" |
+            "SystemString.__op_Equality__SystemString_SystemString__SystemBoolean" |
+            "SystemObject.__op_Equality__SystemObject_SystemObject__SystemBoolean" =>
+                self.call_binary(|l, r| (l == r).to_string()),
+            "SystemObject.__op_Inequality__SystemObject_SystemObject__SystemBoolean" =>
+                self.call_binary(|l, r| (l != r).to_string()),
+            "SystemInt32.__op_GreaterThanOrEqual__SystemInt32_SystemInt32__SystemBoolean" =>
+                self.call_binary(|l, r| (Self::int_value(l) >= Self::int_value(r)).to_string()),
+            "SystemInt32.__op_LessThanOrEqual__SystemInt32_SystemInt32__SystemBoolean" =>
+                self.call_binary(|l, r| (Self::int_value(l) <= Self::int_value(r)).to_string()),
+            "SystemInt32.__op_Subtraction__SystemInt32_SystemInt32__SystemInt32" =>
+                self.call_binary(|l, r| (Self::int_value(l) - Self::int_value(r)).to_string()),
+            "SystemUInt32.__op_Multiplication__SystemUInt32_SystemUInt32__SystemUInt32" =>
+                self.call_binary(|l, r| (Self::int_value(l) * Self::int_value(r)).to_string()),
+            "SystemUInt32.__op_Addition__SystemUInt32_SystemUInt32__SystemUInt32" =>
+                self.call_binary(|l, r| (Self::int_value(l) + Self::int_value(r)).to_string()),
+            "SystemConvert.__ToUInt32__SystemInt32__SystemUInt32" =>
+                self.call_unary(|x| (Self::int_value(x) as u32).to_string()),
+            "SystemConvert.__ToInt32__SystemInt16__SystemInt32" |
+            "SystemConvert.__ToInt64__SystemInt32__SystemInt64" |
+            "SystemConvert.__ToSingle__SystemInt32__SystemSingle" |
+            "SystemConvert.__ToDouble__SystemSingle__SystemDouble" =>
+                self.call_unary(|x| x.to_owned()),
+            "UnityEngineMathf.__Abs__SystemInt32__SystemInt32" |
+            "UnityEngineMathf.__Abs__SystemSingle__SystemSingle" =>
+                self.call_unary(|x| x.trim_start_matches('-').to_owned()),
+            "SystemString.__ToUpper__SystemString" =>
+                self.call_unary(|x| x.to_uppercase()),
+            "SystemObject.__ToString__SystemString" =>
+                self.call_unary(|x| x.to_owned()),
+            "SystemString.__Contains__SystemString__SystemBoolean" =>
+                self.call_binary(|l, r| l.contains(r).to_string()),
+            "SystemInt32.__TryParse__SystemString_SystemInt32Ref__SystemBoolean" => {
+                let ret = self.stack.pop().unwrap();
+                let result = self.stack.pop().unwrap();
+                let var = self.stack.pop().unwrap();
+                let value = Self::end_value(&self.var_values[&var]).parse::<i32>();
+                self.var_values.insert(result, value.clone().unwrap_or_default().to_string());
+                self.var_values.insert(ret, value.is_ok().to_string());
+            },
+            "SystemThreadingInterlocked.__Increment__SystemInt32Ref__SystemInt32" => {
+                let ret = self.stack.pop().unwrap();
+                let location = self.stack.pop().unwrap();
+                let value = (Self::int_value(&Self::end_value(&self.var_values[&location])) + 1).to_string();
+                self.var_values.insert(location, value.clone());
+                self.var_values.insert(ret, value);
+            },
+            "UnityEngineVector3.__ctor__SystemSingle_SystemSingle_SystemSingle__UnityEngineVector3" => {
+                let ret = self.stack.pop().unwrap();
+                let z = self.stack.pop().unwrap();
+                let y = self.stack.pop().unwrap();
+                let x = self.stack.pop().unwrap();
+                let value = format!(
+                    "({}, {}, {})",
+                    Self::end_value(&self.var_values[&x]),
+                    Self::end_value(&self.var_values[&y]),
+                    Self::end_value(&self.var_values[&z])
+                );
+                self.var_values.insert(ret, value);
+            },
+            "UnityEngineObject.__FindObjectOfType__SystemType__UnityEngineObject" =>
+                self.call_unary(|x| x.to_owned()),
+            "UnityEngineComponent.__GetComponent__SystemType__UnityEngineComponent" =>
+                self.call_binary(|l, r| format!("{}.{}", l, r)),
+            "UnityEngineComponent.__get_gameObject__UnityEngineGameObject" =>
+                self.call_unary(|x| format!("{}.gameObject", x)),
+            "UnityEngineComponent.__get_transform__UnityEngineTransform" =>
+                self.call_unary(|x| format!("{}.transform", x)),
+            "VRCUdonUdonBehaviour.__SendCustomEvent__SystemString__SystemVoid" => {
+                let event_name = self.stack.pop().unwrap();
+                self.stack.pop().unwrap();
+                let label = Self::end_value(&self.var_values[&event_name]);
+                self.run(&label);
+            },
+            "UnityEngineVector3.__get_zero__UnityEngineVector3" => {
+                let ret = self.stack.pop().unwrap();
+                self.var_values.insert(ret, "(0, 0, 0)".to_owned());
+            },
             _ => ()
         }
     }
 
+    fn call_unary(&mut self, f: impl Fn(&str) -> String) {
+        let ret = self.stack.pop().unwrap();
+        let var = self.stack.pop().unwrap();
+        let value = Self::end_value(&self.var_values[&var]);
+        self.var_values.insert(ret, f(&value));
+    }
+
+    fn call_binary(&mut self, f: impl Fn(&str, &str) -> String) {
+        let ret = self.stack.pop().unwrap();
+        let right = self.stack.pop().unwrap();
+        let left = self.stack.pop().unwrap();
+        let left_value = Self::end_value(&self.var_values[&left]);
+        let right_value = Self::end_value(&self.var_values[&right]);
+        self.var_values.insert(ret, f(&left_value, &right_value));
+    }
+
+    fn int_value(value: &str) -> i64 {
+        if value.starts_with("0x") || value.starts_with("0X") {
+            i64::from_str_radix(&value[2..], 16).unwrap()
+        }
+        else {
+            value.parse().unwrap()
+        }
+    }
+
     fn get_addr(&self, label: &str) -> u32 {
          if label.starts_with("0x") {
             u32::from_str_radix(&label[2..], 16).unwrap()
          }
+         else if let Ok(addr) = label.parse() {
+            addr
+         }
          else {
             self.label_addrs[label]
          }
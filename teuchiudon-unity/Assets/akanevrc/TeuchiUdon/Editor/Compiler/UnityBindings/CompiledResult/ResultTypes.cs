@@ -6,22 +6,117 @@ namespace akanevrc.TeuchiUdon.Editor.Compiler
     public class Compiled
     {
         public string output;
-        public string[] errors;
+        public Diagnostic[] errors;
+        public Diagnostic[] warnings;
         public DefaultValue[] default_values;
 
         public Compiled
         (
             string output,
-            string[] errors,
+            Diagnostic[] errors,
+            Diagnostic[] warnings,
             DefaultValue[] default_values
         )
         {
             this.output = output;
             this.errors = errors;
+            this.warnings = warnings;
             this.default_values = default_values;
         }
     }
 
+    [Serializable]
+    public class Diagnostic
+    {
+        public string file;
+        public string severity;
+        public string code;
+        public string message;
+        public Range range;
+        public Label[] labels;
+        public string help;
+        public string rendered;
+
+        public Diagnostic
+        (
+            string file,
+            string severity,
+            string code,
+            string message,
+            Range range,
+            Label[] labels,
+            string help,
+            string rendered
+        )
+        {
+            this.file = file;
+            this.severity = severity;
+            this.code = code;
+            this.message = message;
+            this.range = range;
+            this.labels = labels;
+            this.help = help;
+            this.rendered = rendered;
+        }
+    }
+
+    [Serializable]
+    public class Range
+    {
+        public Location start;
+        public Location end;
+
+        public Range
+        (
+            Location start,
+            Location end
+        )
+        {
+            this.start = start;
+            this.end = end;
+        }
+    }
+
+    [Serializable]
+    public class Location
+    {
+        public int offset;
+        public int line;
+        public int column;
+        public int utf16_column;
+
+        public Location
+        (
+            int offset,
+            int line,
+            int column,
+            int utf16_column
+        )
+        {
+            this.offset = offset;
+            this.line = line;
+            this.column = column;
+            this.utf16_column = utf16_column;
+        }
+    }
+
+    [Serializable]
+    public class Label
+    {
+        public Range range;
+        public string message;
+
+        public Label
+        (
+            Range range,
+            string message
+        )
+        {
+            this.range = range;
+            this.message = message;
+        }
+    }
+
     [Serializable]
     public class DefaultValue
     {
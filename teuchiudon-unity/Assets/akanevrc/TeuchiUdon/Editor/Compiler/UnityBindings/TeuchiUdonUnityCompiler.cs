@@ -1,3 +1,4 @@
+using System;
 using System.Runtime.InteropServices;
 
 namespace akanevrc.TeuchiUdon.Editor.Compiler
@@ -8,6 +9,14 @@ namespace akanevrc.TeuchiUdon.Editor.Compiler
         [return: MarshalAs(UnmanagedType.LPUTF8Str)]
         public static extern string compile([MarshalAs(UnmanagedType.LPUTF8Str)] string input, [MarshalAs(UnmanagedType.LPUTF8Str)] string json);
 
+        [DllImport("Assets/akanevrc/TeuchiUdon/Editor/Compiler/External/teuchiudon_bin.dll")]
+        [return: MarshalAs(UnmanagedType.LPUTF8Str)]
+        public static extern string compile_with_locale([MarshalAs(UnmanagedType.LPUTF8Str)] string input, [MarshalAs(UnmanagedType.LPUTF8Str)] string json, [MarshalAs(UnmanagedType.LPUTF8Str)] string locale);
+
+        [DllImport("Assets/akanevrc/TeuchiUdon/Editor/Compiler/External/teuchiudon_bin.dll")]
+        [return: MarshalAs(UnmanagedType.LPUTF8Str)]
+        public static extern string compile_files([MarshalAs(UnmanagedType.LPArray, ArraySubType = UnmanagedType.LPUTF8Str)] string[] names, [MarshalAs(UnmanagedType.LPArray, ArraySubType = UnmanagedType.LPUTF8Str)] string[] inputs, UIntPtr len, [MarshalAs(UnmanagedType.LPUTF8Str)] string entry, [MarshalAs(UnmanagedType.LPUTF8Str)] string json, [MarshalAs(UnmanagedType.LPUTF8Str)] string locale);
+
         [DllImport("Assets/akanevrc/TeuchiUdon/Editor/Compiler/External/teuchiudon_bin.dll")]
         public static extern void free_str([MarshalAs(UnmanagedType.LPUTF8Str)] string str);
     }
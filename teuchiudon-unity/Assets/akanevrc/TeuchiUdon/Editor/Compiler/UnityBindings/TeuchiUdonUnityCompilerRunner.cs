@@ -1,4 +1,5 @@
 using System;
+using System.IO;
 using System.Linq;
 using UnityEditor;
 using UnityEngine;
@@ -16,6 +17,7 @@ namespace akanevrc.TeuchiUdon.Editor.Compiler
     public static class TeuchiUdonUnityCompilerRunner
     {
         private static UdonSymbolExtractor UdonSymbolExtractor { get; } = new UdonSymbolExtractor();
+        private static string EditorLocale => Application.systemLanguage == SystemLanguage.Japanese ? "ja" : "en";
 
         public static TeuchiUdonProgramAsset SaveTeuchiUdonAsset(string srcPath, string assetPath)
         {
@@ -55,16 +57,23 @@ namespace akanevrc.TeuchiUdon.Editor.Compiler
             {
                 return ("", new string[] { "Asset file not found" }, new (string name, Type type, object value)[0]);
             }
-            return CompileFromText(script.text);
+            var dir = Path.GetDirectoryName(path);
+            var files = Directory.GetFiles(dir, "*.teuchi", SearchOption.AllDirectories)
+                .Select(x => x.Replace('\\', '/'))
+                .Select(x => (name: x.Substring(dir.Length + 1), text: x == path ? script.text : File.ReadAllText(x)))
+                .ToArray();
+            return CompileFromFiles(files, Path.GetFileName(path));
         }
 
-        private static (string output, string[] errors, (string name, Type type, object value)[] defaultValues) CompileFromText(string text)
+        private static (string output, string[] errors, (string name, Type type, object value)[] defaultValues) CompileFromFiles((string name, string text)[] files, string entry)
         {
             var output = (string)null;
             try
             {
                 var json = UdonSymbolJsonConverter.ToJson(UdonSymbolExtractor.ExtractSymbols());
-                output = TeuchiUdonUnityCompiler.compile(text, json);
+                var names = files.Select(x => x.name).ToArray();
+                var inputs = files.Select(x => x.text).ToArray();
+                output = TeuchiUdonUnityCompiler.compile_files(names, inputs, (UIntPtr)files.Length, entry, json, EditorLocale);
 
                 var parsed = ParseOutput(output);
                 return parsed;
@@ -88,7 +97,12 @@ namespace akanevrc.TeuchiUdon.Editor.Compiler
                     var (ty, value) = TypeConverter.Convert(x.ty, x.value);
                     return (x.name, ty, value);
                 }).ToArray();
-                return (compiled.output, compiled.errors, defaultValues);
+                foreach (var warn in compiled.warnings)
+                {
+                    Debug.LogWarning(warn.rendered);
+                }
+                var errors = compiled.errors.Select(x => x.rendered).ToArray();
+                return (compiled.output, errors, defaultValues);
             }
         }
     }